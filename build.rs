@@ -18,9 +18,10 @@ mod bundled {
 
     pub fn build_itext() -> Result<()> {
         println!("cargo:rerun-if-changed=bundle");
+        println!("cargo:rerun-if-env-changed=ITEXT_TYPOGRAPHY_JAR");
 
         let manifest_dir = PathBuf::from(var("CARGO_MANIFEST_DIR")?);
-        run_gradle_command("shadowjar")?;
+        run_gradle_command("shadowjar", &gradle_properties()?)?;
 
         let builddir = manifest_dir.join("bundle").join("build").join("libs");
 
@@ -43,11 +44,39 @@ mod bundled {
         let outdir = PathBuf::from(var("OUT_DIR")?);
         fs::copy(outjar, outdir.join("dependencies.jar"))?;
 
-        run_gradle_command("clean")?;
+        run_gradle_command("clean", &[])?;
 
         Ok(())
     }
 
+    /// Project properties selecting the optional iText modules to bundle, based on the enabled features.
+    fn gradle_properties() -> Result<Vec<String>> {
+        let mut properties = Vec::new();
+
+        if var("CARGO_FEATURE_FORMS").is_ok() {
+            properties.push("-Pforms".to_string());
+        }
+
+        if var("CARGO_FEATURE_PDFUA").is_ok() {
+            properties.push("-Ppdfua".to_string());
+        }
+
+        if var("CARGO_FEATURE_TYPOGRAPHY").is_ok() {
+            // The typography add-on is not available from Maven Central, so it must be provided locally
+            match var("ITEXT_TYPOGRAPHY_JAR") {
+                Ok(path) => {
+                    let path = PathBuf::from(path).canonicalize()?;
+                    properties.push(format!("-PtypographyJar={}", path.display()));
+                }
+                Err(_) => println!(
+                    "cargo:warning=The typography feature is enabled, but ITEXT_TYPOGRAPHY_JAR is not set. The typography add-on will not be bundled."
+                ),
+            }
+        }
+
+        Ok(properties)
+    }
+
     fn gradle_command_name() -> &'static str {
         cfg_if! {
             if #[cfg(unix)] {
@@ -60,13 +89,14 @@ mod bundled {
         }
     }
 
-    fn run_gradle_command(cmd: &str) -> Result<()> {
+    fn run_gradle_command(cmd: &str, properties: &[String]) -> Result<()> {
         let manifest_dir = PathBuf::from(var("CARGO_MANIFEST_DIR")?);
         let exec_dir = manifest_dir.join("bundle").canonicalize()?;
         let program = exec_dir.join(gradle_command_name());
 
         let output = Command::new(&program)
             .arg(cmd)
+            .args(properties)
             .current_dir(&exec_dir)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -0,0 +1,68 @@
+package nl.mrfriendly.itextrs;
+
+import com.itextpdf.kernel.colors.Color;
+import com.itextpdf.kernel.colors.ColorConstants;
+import com.itextpdf.kernel.geom.Rectangle;
+import com.itextpdf.kernel.pdf.canvas.PdfCanvas;
+import com.itextpdf.kernel.pdf.canvas.draw.ILineDrawer;
+
+import java.lang.ref.Cleaner;
+
+/**
+ * A line drawer calling back into a Rust closure to draw the line.
+ * The native methods are registered by the Rust side before the first instance is created.
+ */
+public class RustLineDrawer implements ILineDrawer {
+    private static final Cleaner CLEANER = Cleaner.create();
+
+    private final long handle;
+    private float lineWidth = 1;
+    private Color color = ColorConstants.BLACK;
+
+    public RustLineDrawer(long handle) {
+        this.handle = handle;
+        CLEANER.register(this, new Release(handle));
+    }
+
+    @Override
+    public void draw(PdfCanvas canvas, Rectangle drawArea) {
+        draw(handle, this, canvas, drawArea);
+    }
+
+    @Override
+    public float getLineWidth() {
+        return lineWidth;
+    }
+
+    @Override
+    public void setLineWidth(float lineWidth) {
+        this.lineWidth = lineWidth;
+    }
+
+    @Override
+    public Color getColor() {
+        return color;
+    }
+
+    @Override
+    public void setColor(Color color) {
+        this.color = color;
+    }
+
+    private static native void draw(long handle, RustLineDrawer drawer, PdfCanvas canvas, Rectangle drawArea);
+
+    private static native void release(long handle);
+
+    private static class Release implements Runnable {
+        private final long handle;
+
+        Release(long handle) {
+            this.handle = handle;
+        }
+
+        @Override
+        public void run() {
+            release(handle);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+package nl.mrfriendly.itextrs;
+
+import com.itextpdf.io.font.otf.GlyphLine;
+import com.itextpdf.layout.splitting.ISplitCharacters;
+
+import java.lang.ref.Cleaner;
+
+/**
+ * Split characters calling back into a Rust closure to decide where lines may be broken.
+ * The native methods are registered by the Rust side before the first instance is created.
+ */
+public class RustSplitCharacters implements ISplitCharacters {
+    private static final Cleaner CLEANER = Cleaner.create();
+
+    private final long handle;
+
+    public RustSplitCharacters(long handle) {
+        this.handle = handle;
+        CLEANER.register(this, new Release(handle));
+    }
+
+    @Override
+    public boolean isSplitCharacter(GlyphLine text, int glyphPos) {
+        return isSplitCharacter(handle, unicodeAt(text, glyphPos - 1), unicodeAt(text, glyphPos), unicodeAt(text, glyphPos + 1));
+    }
+
+    /**
+     * The code point of the glyph at the position, or -1 if there is none.
+     */
+    private static int unicodeAt(GlyphLine text, int pos) {
+        if (pos < 0 || pos >= text.size()) {
+            return -1;
+        }
+        return text.get(pos).getUnicode();
+    }
+
+    private static native boolean isSplitCharacter(long handle, int previous, int current, int next);
+
+    private static native void release(long handle);
+
+    private static class Release implements Runnable {
+        private final long handle;
+
+        Release(long handle) {
+            this.handle = handle;
+        }
+
+        @Override
+        public void run() {
+            release(handle);
+        }
+    }
+}
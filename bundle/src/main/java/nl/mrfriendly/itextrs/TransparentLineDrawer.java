@@ -0,0 +1,48 @@
+package nl.mrfriendly.itextrs;
+
+import com.itextpdf.kernel.colors.Color;
+import com.itextpdf.kernel.geom.Rectangle;
+import com.itextpdf.kernel.pdf.canvas.PdfCanvas;
+import com.itextpdf.kernel.pdf.canvas.draw.ILineDrawer;
+import com.itextpdf.kernel.pdf.extgstate.PdfExtGState;
+
+/**
+ * A line drawer drawing another line drawer with the given opacity.
+ */
+public class TransparentLineDrawer implements ILineDrawer {
+    private final ILineDrawer drawer;
+    private final float opacity;
+
+    public TransparentLineDrawer(ILineDrawer drawer, float opacity) {
+        this.drawer = drawer;
+        this.opacity = opacity;
+    }
+
+    @Override
+    public void draw(PdfCanvas canvas, Rectangle drawArea) {
+        canvas.saveState()
+                .setExtGState(new PdfExtGState().setStrokeOpacity(opacity).setFillOpacity(opacity));
+        drawer.draw(canvas, drawArea);
+        canvas.restoreState();
+    }
+
+    @Override
+    public float getLineWidth() {
+        return drawer.getLineWidth();
+    }
+
+    @Override
+    public void setLineWidth(float lineWidth) {
+        drawer.setLineWidth(lineWidth);
+    }
+
+    @Override
+    public Color getColor() {
+        return drawer.getColor();
+    }
+
+    @Override
+    public void setColor(Color color) {
+        drawer.setColor(color);
+    }
+}
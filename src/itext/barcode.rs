@@ -1,9 +1,263 @@
-use crate::itext::kernel::{Color, PdfDocument, PdfFormXObject};
+//! Bindings to the iText barcodes module, with one-dimensional barcodes such as EAN and Code 128,
+//! and the two-dimensional barcodes QR code, PDF417 and DataMatrix.
+//!
+//! Micro QR codes are not supported, as iText 9 has no Micro QR implementation.
+
+use crate::itext::io::new_byte_array;
+use crate::itext::kernel::{Color, PdfCanvas, PdfDocument, PdfFont, PdfFormXObject, Rectangle};
+use crate::itext::layout::Image;
+use crate::java::{AwtColor, BufferedImage};
 use crate::java_object;
+use jni::objects::{JByteArray, JObject};
+use jni::sys::jint;
 use jni::JNIEnv;
+use std::fmt;
 
 java_object!(BarcodeEAN);
+java_object!(Barcode128);
+java_object!(Barcode39);
+java_object!(BarcodeCodabar);
+java_object!(BarcodeInter25);
+java_object!(BarcodeMSI);
+java_object!(BarcodePostnet);
+java_object!(BarcodeQRCode);
+java_object!(BarcodePDF417);
+java_object!(BarcodeDataMatrix);
+
+/// Functionality shared by all one-dimensional barcodes.
+///
+/// Sizes are in points. Depending on the barcode type, some settings are ignored.
+pub trait Barcode1D<'a>
+where
+    Self: AsRef<JObject<'a>>,
+{
+    fn set_code(&self, code: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        let code = env.new_string(code)?;
+        env.call_method(
+            self.as_ref(),
+            "setCode",
+            "(Ljava/lang/String;)V",
+            &[(&code).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the width of the narrowest bar, the module width.
+    fn set_x(&self, x: f32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self.as_ref(), "setX", "(F)V", &[x.into()])?;
+        Ok(self)
+    }
+
+    /// Set the ratio between wide and narrow bars.
+    fn set_n(&self, n: f32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self.as_ref(), "setN", "(F)V", &[n.into()])?;
+        Ok(self)
+    }
+
+    fn set_bar_height(&self, bar_height: f32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self.as_ref(), "setBarHeight", "(F)V", &[bar_height.into()])?;
+        Ok(self)
+    }
+
+    /// Set the distance between the bars and the text baseline.
+    /// A negative value places the text above the bars.
+    fn set_baseline(&self, baseline: f32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self.as_ref(), "setBaseline", "(F)V", &[baseline.into()])?;
+        Ok(self)
+    }
+
+    /// Set the font of the human-readable text. `None` hides the text.
+    fn set_font(
+        &self,
+        font: Option<&PdfFont<'a>>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let null = JObject::null();
+        let font = font.map(AsRef::as_ref).unwrap_or(&null);
+        env.call_method(
+            self.as_ref(),
+            "setFont",
+            "(Lcom/itextpdf/kernel/font/PdfFont;)V",
+            &[font.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the font size of the human-readable text.
+    fn set_size(&self, size: f32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self.as_ref(), "setSize", "(F)V", &[size.into()])?;
+        Ok(self)
+    }
+
+    fn set_text_alignment(
+        &self,
+        alignment: BarcodeTextAlignment,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let alignment = alignment.get_java_value(env)?;
+        env.call_method(
+            self.as_ref(),
+            "setTextAlignment",
+            "(I)V",
+            &[alignment.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Whether guard bars are drawn longer than the other bars.
+    fn set_guard_bars(&self, guard_bars: bool, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self.as_ref(), "setGuardBars", "(Z)V", &[guard_bars.into()])?;
+        Ok(self)
+    }
+
+    fn set_generate_checksum(
+        &self,
+        generate_checksum: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setGenerateChecksum",
+            "(Z)V",
+            &[generate_checksum.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Whether the generated checksum is shown in the human-readable text.
+    fn set_checksum_text(
+        &self,
+        checksum_text: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setChecksumText",
+            "(Z)V",
+            &[checksum_text.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the text shown instead of the code. An empty string hides the text.
+    fn set_alt_text(&self, alt_text: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        let alt_text = env.new_string(alt_text)?;
+        env.call_method(
+            self.as_ref(),
+            "setAltText",
+            "(Ljava/lang/String;)V",
+            &[(&alt_text).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Get the size of the barcode, including the human-readable text.
+    fn get_barcode_size(&self, env: &mut JNIEnv<'a>) -> jni::errors::Result<Rectangle<'a>> {
+        let obj = env
+            .call_method(
+                self.as_ref(),
+                "getBarcodeSize",
+                "()Lcom/itextpdf/kernel/geom/Rectangle;",
+                &[],
+            )?
+            .l()?;
+        Ok(Rectangle(obj))
+    }
+
+    fn create_form_x_object(
+        &self,
+        color_bar: &Color<'a>,
+        color_text: &Color<'a>,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<PdfFormXObject<'a>> {
+        let obj = env
+            .call_method(
+                self.as_ref(),
+                "createFormXObject",
+                "(Lcom/itextpdf/kernel/colors/Color;Lcom/itextpdf/kernel/colors/Color;Lcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+                &[color_bar.into(), color_text.into(), pdf_document.into()],
+            )?
+            .l()?;
+        Ok(PdfFormXObject(obj))
+    }
+
+    /// Create a layout image with the barcode, ready to be added to e.g. a `Cell` or `Document`.
+    fn to_layout_image(
+        &self,
+        pdf_document: &PdfDocument<'a>,
+        color_bar: &Color<'a>,
+        color_text: &Color<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<Image<'a>> {
+        let x_object =
+            Barcode1D::create_form_x_object(self, color_bar, color_text, pdf_document, env)?;
+        Image::new_from_x_object(&x_object, env)
+    }
 
+    /// Render the bars to a raster image, with one pixel per module width.
+    /// The human-readable text is not included.
+    fn create_awt_image(
+        &self,
+        foreground: &AwtColor<'a>,
+        background: &AwtColor<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<BufferedImage<'a>> {
+        let image = env
+            .call_method(
+                self.as_ref(),
+                "createAwtImage",
+                "(Ljava/awt/Color;Ljava/awt/Color;)Ljava/awt/Image;",
+                &[foreground.into(), background.into()],
+            )?
+            .l()?;
+        BufferedImage::from_awt_image(&image, env)
+    }
+
+    /// Create a form XObject with an empty margin of `quiet_zone` points left and right of the barcode.
+    fn create_form_x_object_with_quiet_zone(
+        &self,
+        color_bar: &Color<'a>,
+        color_text: &Color<'a>,
+        quiet_zone: f32,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<PdfFormXObject<'a>> {
+        let barcode =
+            Barcode1D::create_form_x_object(self, color_bar, color_text, pdf_document, env)?;
+        let width = barcode.get_width(env)?;
+        let height = barcode.get_height(env)?;
+
+        let bbox = Rectangle::new_w_h(width + 2.0 * quiet_zone, height, env)?;
+        let x_object = PdfFormXObject::new(&bbox, env)?;
+        let canvas = PdfCanvas::new_with_form_x_object(&x_object, pdf_document, env)?;
+        canvas.add_x_object_at_form(&barcode, quiet_zone, 0.0, env)?;
+        canvas.release(env)?;
+
+        Ok(x_object)
+    }
+}
+
+#[derive(Clone)]
+pub enum BarcodeTextAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl BarcodeTextAlignment {
+    fn get_java_value(&self, env: &mut JNIEnv) -> jni::errors::Result<i32> {
+        let field_name = match self {
+            Self::Left => "ALIGN_LEFT",
+            Self::Center => "ALIGN_CENTER",
+            Self::Right => "ALIGN_RIGHT",
+        };
+        env.get_static_field("com/itextpdf/barcodes/Barcode1D", field_name, "I")?
+            .i()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BarcodeType {
     Ean13,
     Ean8,
@@ -13,6 +267,8 @@ pub enum BarcodeType {
     Upce,
 }
 
+impl<'a> Barcode1D<'a> for BarcodeEAN<'a> {}
+
 impl<'a> BarcodeEAN<'a> {
     fn barcode_type_value(
         barcode_type: &BarcodeType,
@@ -85,4 +341,1425 @@ impl<'a> BarcodeEAN<'a> {
 
         Ok(PdfFormXObject(obj))
     }
+
+    /// Validate the code for the barcode type before setting both on the barcode.
+    /// Unlike [BarcodeEAN::set_code], an invalid code is rejected instead of being drawn incorrectly.
+    pub fn set_code_checked(
+        &self,
+        barcode_type: &BarcodeType,
+        code: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self, EanError> {
+        barcode_type.validate_code(code)?;
+        self.set_code_type(barcode_type, env)?;
+        BarcodeEAN::set_code(self, code, env)?;
+        Ok(self)
+    }
+
+    /// Calculate the check digit of an EAN or UPC code, excluding the check digit.
+    pub fn calculate_ean_parity(code: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<i32> {
+        let code = env.new_string(code)?;
+        env.call_static_method(
+            "com/itextpdf/barcodes/BarcodeEAN",
+            "calculateEANParity",
+            "(Ljava/lang/String;)I",
+            &[(&code).into()],
+        )?
+        .i()
+    }
+
+    /// Convert a UPC-E code to UPC-A. Returns `None` if the code cannot be converted.
+    pub fn convert_upce_to_upca(
+        code: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<Option<String>> {
+        let code = env.new_string(code)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/barcodes/BarcodeEAN",
+                "convertUPCEtoUPCA",
+                "(Ljava/lang/String;)Ljava/lang/String;",
+                &[(&code).into()],
+            )?
+            .l()?;
+
+        if obj.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(env.get_string(&obj.into())?.into()))
+    }
+
+    /// Get the widths of the bars and spaces for the code, in modules.
+    pub fn get_bars(
+        barcode_type: &BarcodeType,
+        code: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<Vec<u8>> {
+        let method = match barcode_type {
+            BarcodeType::Ean13 | BarcodeType::Upca => "getBarsEAN13",
+            BarcodeType::Ean8 => "getBarsEAN8",
+            BarcodeType::Upce => "getBarsUPCE",
+            BarcodeType::Supp2 => "getBarsSupplemental2",
+            BarcodeType::Supp5 => "getBarsSupplemental5",
+        };
+
+        // UPC-A is encoded as EAN-13 with a leading zero
+        let code = match barcode_type {
+            BarcodeType::Upca => format!("0{code}"),
+            _ => code.to_string(),
+        };
+
+        let code = env.new_string(code)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/barcodes/BarcodeEAN",
+                method,
+                "(Ljava/lang/String;)[B",
+                &[(&code).into()],
+            )?
+            .l()?;
+        let obj_arr: JByteArray = obj.into();
+        let size = env.get_array_length(&obj_arr)?;
+
+        let mut buf = vec![0; size as usize];
+        env.get_byte_array_region(&obj_arr, 0, &mut buf)?;
+
+        Ok(buf.into_iter().map(|x| x as u8).collect())
+    }
+}
+
+#[derive(Debug)]
+pub enum EanError {
+    /// The code does not have the number of digits required by the barcode type.
+    InvalidLength {
+        expected: usize,
+        length: usize,
+    },
+    /// The code contains a character which is not a digit.
+    InvalidCharacter(char),
+    /// The check digit of the code is incorrect.
+    InvalidCheckDigit {
+        expected: u8,
+        found: u8,
+    },
+    /// A UPC-E code must use number system 0 or 1.
+    InvalidNumberSystem(u8),
+    Jni(jni::errors::Error),
+}
+
+impl fmt::Display for EanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, length } => {
+                write!(f, "Expected a code of {expected} digits, got {length}")
+            }
+            Self::InvalidCharacter(c) => write!(f, "Invalid character '{c}' in code"),
+            Self::InvalidCheckDigit { expected, found } => {
+                write!(f, "Invalid check digit {found}, expected {expected}")
+            }
+            Self::InvalidNumberSystem(number_system) => {
+                write!(f, "Invalid UPC-E number system {number_system}")
+            }
+            Self::Jni(e) => write!(f, "JNI error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for EanError {}
+
+impl From<jni::errors::Error> for EanError {
+    fn from(value: jni::errors::Error) -> Self {
+        Self::Jni(value)
+    }
+}
+
+impl BarcodeType {
+    /// Get the number of digits of a code, including the check digit.
+    pub fn code_length(&self) -> usize {
+        match self {
+            Self::Ean13 => 13,
+            Self::Ean8 => 8,
+            Self::Upca => 12,
+            Self::Upce => 8,
+            Self::Supp2 => 2,
+            Self::Supp5 => 5,
+        }
+    }
+
+    /// Validate the length and check digit of a code.
+    /// Supplemental codes have no check digit, so only their length is validated.
+    pub fn validate_code(&self, code: &str) -> Result<(), EanError> {
+        if let Some(c) = code.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(EanError::InvalidCharacter(c));
+        }
+
+        if code.len() != self.code_length() {
+            return Err(EanError::InvalidLength {
+                expected: self.code_length(),
+                length: code.len(),
+            });
+        }
+
+        let (digits, check_digit) = match self {
+            Self::Supp2 | Self::Supp5 => return Ok(()),
+            // The check digit of UPC-E is calculated over the equivalent UPC-A code
+            Self::Upce => {
+                let upca = upce_to_upca(code)?;
+                (upca[..11].to_string(), &code[7..])
+            }
+            _ => (code[..code.len() - 1].to_string(), &code[code.len() - 1..]),
+        };
+
+        // Both are known to consist of ASCII digits
+        let expected = gs1_check_digit(&digits).unwrap_or_default();
+        let found = check_digit.parse().unwrap_or_default();
+        if expected != found {
+            return Err(EanError::InvalidCheckDigit { expected, found });
+        }
+
+        Ok(())
+    }
+}
+
+/// Expand an 8 digit UPC-E code to the equivalent 12 digit UPC-A code.
+/// The check digit of the UPC-E code is copied as-is.
+pub fn upce_to_upca(code: &str) -> Result<String, EanError> {
+    if let Some(c) = code.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(EanError::InvalidCharacter(c));
+    }
+
+    if code.len() != 8 {
+        return Err(EanError::InvalidLength {
+            expected: 8,
+            length: code.len(),
+        });
+    }
+
+    let number_system = &code[0..1];
+    if number_system != "0" && number_system != "1" {
+        return Err(EanError::InvalidNumberSystem(
+            number_system.parse().unwrap_or_default(),
+        ));
+    }
+
+    let d = &code[1..7];
+    let check_digit = &code[7..];
+    let manufacturer_product = match &d[5..6] {
+        "0" | "1" | "2" => format!("{}{}0000{}", &d[0..2], &d[5..6], &d[2..5]),
+        "3" => format!("{}00000{}", &d[0..3], &d[3..5]),
+        "4" => format!("{}00000{}", &d[0..4], &d[4..5]),
+        _ => format!("{}0000{}", &d[0..5], &d[5..6]),
+    };
+
+    Ok(format!(
+        "{number_system}{manufacturer_product}{check_digit}"
+    ))
+}
+
+/// Functionality shared by all two-dimensional barcodes.
+pub trait Barcode2D<'a>
+where
+    Self: AsRef<JObject<'a>>,
+{
+    /// Create a form XObject with the barcode, using the default module size.
+    fn create_form_x_object(
+        &self,
+        foreground: &Color<'a>,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<PdfFormXObject<'a>> {
+        let obj = env
+            .call_method(
+                self.as_ref(),
+                "createFormXObject",
+                "(Lcom/itextpdf/kernel/colors/Color;Lcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+                &[foreground.into(), pdf_document.into()],
+            )?
+            .l()?;
+        Ok(PdfFormXObject(obj))
+    }
+
+    /// Create a layout image with the barcode, using the default module size.
+    fn to_layout_image(
+        &self,
+        pdf_document: &PdfDocument<'a>,
+        foreground: &Color<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<Image<'a>> {
+        let x_object = self.create_form_x_object(foreground, pdf_document, env)?;
+        Image::new_from_x_object(&x_object, env)
+    }
+
+    /// Render the barcode to a raster image, with one pixel per module.
+    fn create_awt_image(
+        &self,
+        foreground: &AwtColor<'a>,
+        background: &AwtColor<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<BufferedImage<'a>> {
+        let image = env
+            .call_method(
+                self.as_ref(),
+                "createAwtImage",
+                "(Ljava/awt/Color;Ljava/awt/Color;)Ljava/awt/Image;",
+                &[foreground.into(), background.into()],
+            )?
+            .l()?;
+        BufferedImage::from_awt_image(&image, env)
+    }
+
+    /// Get the size of the barcode in modules.
+    fn get_barcode_size(&self, env: &mut JNIEnv<'a>) -> jni::errors::Result<Rectangle<'a>> {
+        let obj = env
+            .call_method(
+                self.as_ref(),
+                "getBarcodeSize",
+                "()Lcom/itextpdf/kernel/geom/Rectangle;",
+                &[],
+            )?
+            .l()?;
+        Ok(Rectangle(obj))
+    }
+}
+
+#[derive(Clone)]
+pub enum QrErrorCorrectionLevel {
+    /// ~7% correction
+    L,
+    /// ~15% correction
+    M,
+    /// ~25% correction
+    Q,
+    /// ~30% correction
+    H,
+}
+
+#[derive(Clone, Default)]
+pub struct QrCodeHints {
+    pub error_correction: Option<QrErrorCorrectionLevel>,
+    /// The minimal QR version to use, from 1 to 40.
+    pub min_version: Option<i32>,
+    /// The character set used to encode the content, e.g. `UTF-8`.
+    pub character_set: Option<String>,
+}
+
+impl<'a> Barcode2D<'a> for BarcodeQRCode<'a> {}
+
+impl<'a> BarcodeQRCode<'a> {
+    pub fn new(code: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<Self> {
+        let code = env.new_string(code)?;
+        let obj = env.new_object(
+            "com/itextpdf/barcodes/BarcodeQRCode",
+            "(Ljava/lang/String;)V",
+            &[(&code).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn set_code(&self, code: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        let code = env.new_string(code)?;
+        env.call_method(self, "setCode", "(Ljava/lang/String;)V", &[(&code).into()])?;
+        Ok(self)
+    }
+
+    pub fn set_hints(
+        &self,
+        hints: &QrCodeHints,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let map = env.new_object("java/util/HashMap", "()V", &[])?;
+
+        if let Some(error_correction) = &hints.error_correction {
+            let level = error_correction.get_java_value(env)?;
+            Self::put_hint(&map, "ERROR_CORRECTION", &level, env)?;
+        }
+
+        if let Some(min_version) = hints.min_version {
+            let version = env
+                .call_static_method(
+                    "java/lang/Integer",
+                    "valueOf",
+                    "(I)Ljava/lang/Integer;",
+                    &[min_version.into()],
+                )?
+                .l()?;
+            Self::put_hint(&map, "MIN_VERSION_NR", &version, env)?;
+        }
+
+        if let Some(character_set) = &hints.character_set {
+            let character_set = env.new_string(character_set)?;
+            Self::put_hint(&map, "CHARACTER_SET", &character_set, env)?;
+        }
+
+        env.call_method(self, "setHints", "(Ljava/util/Map;)V", &[(&map).into()])?;
+        Ok(self)
+    }
+
+    fn put_hint(
+        map: &JObject<'a>,
+        hint_type: &str,
+        value: &JObject<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<()> {
+        let key = env
+            .get_static_field(
+                "com/itextpdf/barcodes/qrcode/EncodeHintType",
+                hint_type,
+                "Lcom/itextpdf/barcodes/qrcode/EncodeHintType;",
+            )?
+            .l()?;
+        env.call_method(
+            map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            &[(&key).into(), value.into()],
+        )?;
+        Ok(())
+    }
+
+    /// Create a form XObject with the barcode, where each module is `module_size` points wide and high.
+    pub fn create_form_x_object_with_module_size(
+        &self,
+        foreground: &Color<'a>,
+        module_size: f32,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<PdfFormXObject<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createFormXObject",
+                "(Lcom/itextpdf/kernel/colors/Color;FLcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+                &[foreground.into(), module_size.into(), pdf_document.into()],
+            )?
+            .l()?;
+        Ok(PdfFormXObject(obj))
+    }
+}
+
+impl QrErrorCorrectionLevel {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> jni::errors::Result<JObject<'a>> {
+        let field_name = match self {
+            Self::L => "L",
+            Self::M => "M",
+            Self::Q => "Q",
+            Self::H => "H",
+        };
+
+        env.get_static_field(
+            "com/itextpdf/barcodes/qrcode/ErrorCorrectionLevel",
+            field_name,
+            "Lcom/itextpdf/barcodes/qrcode/ErrorCorrectionLevel;",
+        )?
+        .l()
+    }
+}
+
+impl<'a> Barcode2D<'a> for BarcodePDF417<'a> {}
+
+impl<'a> BarcodePDF417<'a> {
+    pub fn new(env: &mut JNIEnv<'a>) -> jni::errors::Result<Self> {
+        let obj = env.new_object("com/itextpdf/barcodes/BarcodePDF417", "()V", &[])?;
+        Ok(Self(obj))
+    }
+
+    pub fn set_code(&self, code: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        let code = env.new_string(code)?;
+        env.call_method(self, "setCode", "(Ljava/lang/String;)V", &[(&code).into()])?;
+        Ok(self)
+    }
+
+    /// Use a fixed number of data columns. By default, the number of columns is derived from the aspect ratio.
+    pub fn set_code_columns(
+        &self,
+        columns: i32,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        self.add_option("PDF417_FIXED_COLUMNS", env)?;
+        env.call_method(self, "setCodeColumns", "(I)V", &[columns.into()])?;
+        Ok(self)
+    }
+
+    /// Use a fixed error correction level, from 0 to 8. By default, the level is derived from the data length.
+    pub fn set_error_level(&self, level: i32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        self.add_option("PDF417_USE_ERROR_LEVEL", env)?;
+        env.call_method(self, "setErrorLevel", "(I)V", &[level.into()])?;
+        Ok(self)
+    }
+
+    /// Set the height to width ratio of the barcode, used to derive the number of columns.
+    pub fn set_aspect_ratio(
+        &self,
+        aspect_ratio: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        env.call_method(self, "setAspectRatio", "(F)V", &[aspect_ratio.into()])?;
+        Ok(self)
+    }
+
+    /// Set the height to width ratio of a single module.
+    pub fn set_y_height(&self, y_height: f32, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self, "setYHeight", "(F)V", &[y_height.into()])?;
+        Ok(self)
+    }
+
+    fn add_option(&self, option: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<()> {
+        let flag = env
+            .get_static_field("com/itextpdf/barcodes/BarcodePDF417", option, "I")?
+            .i()?;
+        let options = env.call_method(self, "getOptions", "()I", &[])?.i()?;
+        env.call_method(self, "setOptions", "(I)V", &[(options | flag).into()])?;
+        Ok(())
+    }
+
+    /// Create a form XObject with the barcode, where each module is `module_width` by `module_height` points.
+    pub fn create_form_x_object_with_module_size(
+        &self,
+        foreground: &Color<'a>,
+        module_width: f32,
+        module_height: f32,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<PdfFormXObject<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createFormXObject",
+                "(Lcom/itextpdf/kernel/colors/Color;FFLcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+                &[
+                    foreground.into(),
+                    module_width.into(),
+                    module_height.into(),
+                    pdf_document.into(),
+                ],
+            )?
+            .l()?;
+        Ok(PdfFormXObject(obj))
+    }
+}
+
+#[derive(Clone)]
+pub enum DataMatrixEncodation {
+    Auto,
+    Ascii,
+    C40,
+    Text,
+    B256,
+    X12,
+    Edifact,
+    Raw,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataMatrixStatus {
+    NoError,
+    TextTooBig,
+    InvalidSquare,
+    Extension,
+}
+
+impl<'a> Barcode2D<'a> for BarcodeDataMatrix<'a> {}
+
+impl<'a> BarcodeDataMatrix<'a> {
+    /// Encode a GS1 element string as GS1 DataMatrix.
+    /// Note that this sets the encodation to [DataMatrixEncodation::Raw].
+    pub fn set_gs1(
+        &self,
+        element: &Gs1Element,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<DataMatrixStatus> {
+        self.set_encodation(DataMatrixEncodation::Raw, env)?;
+
+        // Pass the codewords as bytes, as a string would be converted with the configured encoding
+        let codewords = element.to_data_matrix_codewords();
+        let length = codewords.len() as jint;
+        let codewords = new_byte_array(&codewords, env)?;
+        let status = env
+            .call_method(
+                self,
+                "setCode",
+                "([BII)I",
+                &[(&codewords).into(), 0.into(), length.into()],
+            )?
+            .i()?;
+        DataMatrixStatus::from_java_value(status, env)
+    }
+
+    pub fn new(env: &mut JNIEnv<'a>) -> jni::errors::Result<Self> {
+        let obj = env.new_object("com/itextpdf/barcodes/BarcodeDataMatrix", "()V", &[])?;
+        Ok(Self(obj))
+    }
+
+    /// Set the content of the barcode. Returns whether the content could be encoded
+    /// with the current size and encodation.
+    pub fn set_code(
+        &self,
+        code: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<DataMatrixStatus> {
+        let code = env.new_string(code)?;
+        let status = env
+            .call_method(self, "setCode", "(Ljava/lang/String;)I", &[(&code).into()])?
+            .i()?;
+        DataMatrixStatus::from_java_value(status, env)
+    }
+
+    /// Use a fixed symbol size. By default, the smallest size fitting the data is chosen.
+    pub fn set_size(
+        &self,
+        height: i32,
+        width: i32,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        env.call_method(self, "setHeight", "(I)V", &[height.into()])?;
+        env.call_method(self, "setWidth", "(I)V", &[width.into()])?;
+        Ok(self)
+    }
+
+    pub fn set_encodation(
+        &self,
+        encodation: DataMatrixEncodation,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let field_name = match encodation {
+            DataMatrixEncodation::Auto => "DM_AUTO",
+            DataMatrixEncodation::Ascii => "DM_ASCII",
+            DataMatrixEncodation::C40 => "DM_C40",
+            DataMatrixEncodation::Text => "DM_TEXT",
+            DataMatrixEncodation::B256 => "DM_B256",
+            DataMatrixEncodation::X12 => "DM_X12",
+            DataMatrixEncodation::Edifact => "DM_EDIFACT",
+            DataMatrixEncodation::Raw => "DM_RAW",
+        };
+        // The encodation is stored in the lowest three bits of the options, next to flags like DM_TEST
+        const ENCODATION_MASK: i32 = 0x7;
+
+        let encodation = env
+            .get_static_field("com/itextpdf/barcodes/BarcodeDataMatrix", field_name, "I")?
+            .i()?;
+        let options = env.call_method(self, "getOptions", "()I", &[])?.i()?;
+        let options = (options & !ENCODATION_MASK) | encodation;
+        env.call_method(self, "setOptions", "(I)V", &[options.into()])?;
+        Ok(self)
+    }
+
+    /// Set the character encoding used to convert the content to bytes, e.g. `UTF-8`.
+    pub fn set_encoding(&self, encoding: &str, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        let encoding = env.new_string(encoding)?;
+        env.call_method(
+            self,
+            "setEncoding",
+            "(Ljava/lang/String;)V",
+            &[(&encoding).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Create a form XObject with the barcode, where each module is `module_size` points wide and high.
+    pub fn create_form_x_object_with_module_size(
+        &self,
+        foreground: &Color<'a>,
+        module_size: f32,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<PdfFormXObject<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createFormXObject",
+                "(Lcom/itextpdf/kernel/colors/Color;FLcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+                &[foreground.into(), module_size.into(), pdf_document.into()],
+            )?
+            .l()?;
+        Ok(PdfFormXObject(obj))
+    }
+}
+
+impl DataMatrixStatus {
+    fn from_java_value(status: i32, env: &mut JNIEnv) -> jni::errors::Result<Self> {
+        for (field_name, value) in [
+            ("DM_ERROR_TEXT_TOO_BIG", Self::TextTooBig),
+            ("DM_ERROR_INVALID_SQUARE", Self::InvalidSquare),
+            ("DM_ERROR_EXTENSION", Self::Extension),
+        ] {
+            let field = env
+                .get_static_field("com/itextpdf/barcodes/BarcodeDataMatrix", field_name, "I")?
+                .i()?;
+            if field == status {
+                return Ok(value);
+            }
+        }
+
+        Ok(Self::NoError)
+    }
+}
+
+macro_rules! barcode_1d_constructor {
+    ($name:ident, $class:expr) => {
+        impl<'a> Barcode1D<'a> for $name<'a> {}
+
+        impl<'a> $name<'a> {
+            pub fn new(
+                pdf_document: &PdfDocument<'a>,
+                env: &mut JNIEnv<'a>,
+            ) -> jni::errors::Result<Self> {
+                let obj = env.new_object(
+                    $class,
+                    "(Lcom/itextpdf/kernel/pdf/PdfDocument;)V",
+                    &[pdf_document.into()],
+                )?;
+                Ok(Self(obj))
+            }
+        }
+    };
+}
+
+barcode_1d_constructor!(Barcode128, "com/itextpdf/barcodes/Barcode128");
+barcode_1d_constructor!(Barcode39, "com/itextpdf/barcodes/Barcode39");
+barcode_1d_constructor!(BarcodeCodabar, "com/itextpdf/barcodes/BarcodeCodabar");
+barcode_1d_constructor!(BarcodeInter25, "com/itextpdf/barcodes/BarcodeInter25");
+barcode_1d_constructor!(BarcodeMSI, "com/itextpdf/barcodes/BarcodeMSI");
+barcode_1d_constructor!(BarcodePostnet, "com/itextpdf/barcodes/BarcodePostnet");
+
+#[derive(Clone)]
+pub enum Barcode128Type {
+    /// Plain Code 128
+    Code128,
+    /// GS1-128, formerly UCC/EAN-128. Application identifiers are written in parentheses, e.g. `(01)12345678901231`.
+    Code128Ucc,
+    /// The code is a raw sequence of code characters, see [Barcode128::get_raw_text].
+    Code128Raw,
+}
+
+#[derive(Clone)]
+pub enum Barcode128CodeSet {
+    A,
+    B,
+    C,
+    Auto,
+}
+
+impl<'a> Barcode128<'a> {
+    /// Encode a GS1 element string as GS1-128.
+    /// FNC1 separators and the human-readable text are generated by iText.
+    pub fn set_gs1(
+        &self,
+        element: &Gs1Element,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        self.set_code_type(Barcode128Type::Code128Ucc, env)?;
+        Barcode1D::set_code(self, &element.human_readable(), env)?;
+        Ok(self)
+    }
+
+    pub fn set_code_type(
+        &self,
+        code_type: Barcode128Type,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let field_name = match code_type {
+            Barcode128Type::Code128 => "CODE128",
+            Barcode128Type::Code128Ucc => "CODE128_UCC",
+            Barcode128Type::Code128Raw => "CODE128_RAW",
+        };
+        let ty = env
+            .get_static_field("com/itextpdf/barcodes/Barcode128", field_name, "I")?
+            .i()?;
+        env.call_method(self, "setCodeType", "(I)V", &[ty.into()])?;
+        Ok(self)
+    }
+
+    /// Force the code set used to encode the content.
+    pub fn set_code_set(
+        &self,
+        code_set: Barcode128CodeSet,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let code_set = code_set.get_java_value(env)?;
+        env.call_method(
+            self,
+            "setCodeSet",
+            "(Lcom/itextpdf/barcodes/Barcode128$Barcode128CodeSet;)V",
+            &[(&code_set).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Convert text to the raw code characters, for use with [Barcode128Type::Code128Raw].
+    pub fn get_raw_text(
+        text: &str,
+        ucc: bool,
+        code_set: Barcode128CodeSet,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<String> {
+        let text = env.new_string(text)?;
+        let code_set = code_set.get_java_value(env)?;
+        let raw = env
+            .call_static_method(
+                "com/itextpdf/barcodes/Barcode128",
+                "getRawText",
+                "(Ljava/lang/String;ZLcom/itextpdf/barcodes/Barcode128$Barcode128CodeSet;)Ljava/lang/String;",
+                &[(&text).into(), ucc.into(), (&code_set).into()],
+            )?
+            .l()?;
+        Ok(env.get_string(&raw.into())?.into())
+    }
+}
+
+impl Barcode128CodeSet {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> jni::errors::Result<JObject<'a>> {
+        let field_name = match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::Auto => "AUTO",
+        };
+        env.get_static_field(
+            "com/itextpdf/barcodes/Barcode128$Barcode128CodeSet",
+            field_name,
+            "Lcom/itextpdf/barcodes/Barcode128$Barcode128CodeSet;",
+        )?
+        .l()
+    }
+}
+
+impl<'a> Barcode39<'a> {
+    /// Whether to use the extended charset, supporting all ASCII characters.
+    pub fn set_extended(&self, extended: bool, env: &mut JNIEnv<'a>) -> jni::errors::Result<&Self> {
+        env.call_method(self, "setExtended", "(Z)V", &[extended.into()])?;
+        Ok(self)
+    }
+
+    /// Whether the start and stop character `*` is shown in the human-readable text.
+    pub fn set_start_stop_text(
+        &self,
+        start_stop_text: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        env.call_method(self, "setStartStopText", "(Z)V", &[start_stop_text.into()])?;
+        Ok(self)
+    }
+}
+
+impl<'a> BarcodeCodabar<'a> {
+    /// Whether the start and stop characters are shown in the human-readable text.
+    pub fn set_start_stop_text(
+        &self,
+        start_stop_text: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        env.call_method(self, "setStartStopText", "(Z)V", &[start_stop_text.into()])?;
+        Ok(self)
+    }
+}
+
+#[derive(Clone)]
+pub enum PostnetType {
+    Postnet,
+    Planet,
+}
+
+impl<'a> BarcodePostnet<'a> {
+    pub fn set_code_type(
+        &self,
+        code_type: PostnetType,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<&Self> {
+        let field_name = match code_type {
+            PostnetType::Postnet => "TYPE_POSTNET",
+            PostnetType::Planet => "TYPE_PLANET",
+        };
+        let ty = env
+            .get_static_field("com/itextpdf/barcodes/BarcodePostnet", field_name, "I")?
+            .i()?;
+        env.call_method(self, "setCodeType", "(I)V", &[ty.into()])?;
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Gs1Error {
+    /// The application identifier is not known.
+    UnknownApplicationIdentifier(String),
+    /// The data does not have a valid length for the application identifier.
+    InvalidLength { ai: String, length: usize },
+    /// The data contains a character not allowed for the application identifier.
+    InvalidCharacter { ai: String, character: char },
+    /// The check digit of the data is incorrect.
+    InvalidCheckDigit { ai: String },
+    /// The data is not a valid `YYMMDD` date.
+    InvalidDate { ai: String },
+}
+
+impl fmt::Display for Gs1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownApplicationIdentifier(ai) => {
+                write!(f, "Unknown GS1 application identifier ({ai})")
+            }
+            Self::InvalidLength { ai, length } => {
+                write!(
+                    f,
+                    "Invalid data length {length} for GS1 application identifier ({ai})"
+                )
+            }
+            Self::InvalidCharacter { ai, character } => write!(
+                f,
+                "Invalid character '{character}' for GS1 application identifier ({ai})"
+            ),
+            Self::InvalidCheckDigit { ai } => {
+                write!(
+                    f,
+                    "Invalid check digit for GS1 application identifier ({ai})"
+                )
+            }
+            Self::InvalidDate { ai } => {
+                write!(f, "Invalid date for GS1 application identifier ({ai})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Gs1Error {}
+
+#[derive(Clone, Copy)]
+enum Gs1Charset {
+    Numeric,
+    Alphanumeric,
+}
+
+#[derive(Clone, Copy)]
+struct Gs1Format {
+    charset: Gs1Charset,
+    min_length: usize,
+    max_length: usize,
+    check_digit: bool,
+    date: bool,
+}
+
+impl Gs1Format {
+    const fn numeric(min_length: usize, max_length: usize) -> Self {
+        Self {
+            charset: Gs1Charset::Numeric,
+            min_length,
+            max_length,
+            check_digit: false,
+            date: false,
+        }
+    }
+
+    const fn alphanumeric(max_length: usize) -> Self {
+        Self {
+            charset: Gs1Charset::Alphanumeric,
+            min_length: 1,
+            max_length,
+            check_digit: false,
+            date: false,
+        }
+    }
+
+    const fn with_check_digit(self) -> Self {
+        Self {
+            check_digit: true,
+            ..self
+        }
+    }
+
+    const fn with_date(self) -> Self {
+        Self { date: true, ..self }
+    }
+
+    /// Get the format of the data for an application identifier.
+    fn for_ai(ai: &str) -> Option<Self> {
+        let format = match ai {
+            "00" => Self::numeric(18, 18).with_check_digit(),
+            "01" | "02" => Self::numeric(14, 14).with_check_digit(),
+            "10" | "21" | "22" => Self::alphanumeric(20),
+            "11" | "12" | "13" | "15" | "16" | "17" => Self::numeric(6, 6).with_date(),
+            "20" => Self::numeric(2, 2),
+            "30" | "37" => Self::numeric(1, 8),
+            "240" | "241" | "250" | "251" | "400" | "401" | "403" => Self::alphanumeric(30),
+            "402" => Self::numeric(17, 17).with_check_digit(),
+            "410" | "411" | "412" | "413" | "414" | "415" | "416" | "417" => {
+                Self::numeric(13, 13).with_check_digit()
+            }
+            "420" => Self::alphanumeric(20),
+            "422" | "424" | "426" => Self::numeric(3, 3),
+            "8004" => Self::alphanumeric(30),
+            "8005" => Self::numeric(6, 6),
+            "90" => Self::alphanumeric(30),
+            "91" | "92" | "93" | "94" | "95" | "96" | "97" | "98" | "99" => Self::alphanumeric(90),
+            // Measures, where the fourth digit indicates the position of the decimal point
+            _ if ai.len() == 4
+                && ai.chars().all(|c| c.is_ascii_digit())
+                && ("31".."37").contains(&&ai[..2]) =>
+            {
+                Self::numeric(6, 6)
+            }
+            // Amounts, where the fourth digit indicates the position of the decimal point
+            _ if ai.len() == 4 && ai.chars().all(|c| c.is_ascii_digit()) => match &ai[..3] {
+                "390" | "392" => Self::numeric(1, 15),
+                "391" | "393" => Self::numeric(4, 18),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(format)
+    }
+}
+
+/// Whether the application identifier has a predefined length, and thus needs no FNC1 separator.
+fn gs1_has_predefined_length(ai: &str) -> bool {
+    matches!(
+        &ai[..2],
+        "00" | "01"
+            | "02"
+            | "03"
+            | "04"
+            | "11"
+            | "12"
+            | "13"
+            | "14"
+            | "15"
+            | "16"
+            | "17"
+            | "18"
+            | "19"
+            | "20"
+            | "31"
+            | "32"
+            | "33"
+            | "34"
+            | "35"
+            | "36"
+            | "41"
+    )
+}
+
+/// Whether the character is in the GS1 AI encodable character set 82.
+fn gs1_is_encodable(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!\"%&'()*+,-./:;<=>?_".contains(c)
+}
+
+/// Compute the GS1 modulo 10 check digit for the given digits, excluding the check digit.
+/// This is the check digit used by GTIN, SSCC, GLN, EAN and UPC codes.
+///
+/// Returns `None` if `digits` contains a character which is not an ASCII digit.
+pub fn gs1_check_digit(digits: &str) -> Option<u8> {
+    let mut sum = 0u32;
+    for (i, c) in digits.chars().rev().enumerate() {
+        let digit = c.to_digit(10)?;
+        sum += if i % 2 == 0 { digit * 3 } else { digit };
+    }
+
+    Some(((10 - sum % 10) % 10) as u8)
+}
+
+/// A GS1 element string, consisting of one or more application identifiers (AI) with their data.
+///
+/// ```
+/// # use itext::itext::barcode::{Gs1Element, Gs1Error};
+/// # fn main() -> Result<(), Gs1Error> {
+/// let element = Gs1Element::new()
+///     .add("01", "09501101530003")?
+///     .add("17", "251231")?
+///     .add("10", "AB-123")?;
+/// assert_eq!(element.human_readable(), "(01)09501101530003(17)251231(10)AB-123");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Gs1Element {
+    elements: Vec<(String, String)>,
+}
+
+impl Gs1Element {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an application identifier with its data.
+    /// The data is validated against the format of the application identifier.
+    pub fn add(mut self, ai: &str, data: &str) -> Result<Self, Gs1Error> {
+        let format = Gs1Format::for_ai(ai)
+            .ok_or_else(|| Gs1Error::UnknownApplicationIdentifier(ai.to_string()))?;
+
+        let length = data.chars().count();
+        if length < format.min_length || length > format.max_length {
+            return Err(Gs1Error::InvalidLength {
+                ai: ai.to_string(),
+                length,
+            });
+        }
+
+        let invalid_character = data.chars().find(|c| match format.charset {
+            Gs1Charset::Numeric => !c.is_ascii_digit(),
+            Gs1Charset::Alphanumeric => !gs1_is_encodable(*c),
+        });
+        if let Some(character) = invalid_character {
+            return Err(Gs1Error::InvalidCharacter {
+                ai: ai.to_string(),
+                character,
+            });
+        }
+
+        if format.check_digit {
+            let (digits, check_digit) = data.split_at(data.len() - 1);
+            if gs1_check_digit(digits).map(|d| d.to_string()).as_deref() != Some(check_digit) {
+                return Err(Gs1Error::InvalidCheckDigit { ai: ai.to_string() });
+            }
+        }
+
+        if format.date {
+            // Day 00 means the last day of the month
+            let month: u32 = data[2..4].parse().unwrap_or(0);
+            let day: u32 = data[4..6].parse().unwrap_or(99);
+            if !(1..=12).contains(&month) || day > 31 {
+                return Err(Gs1Error::InvalidDate { ai: ai.to_string() });
+            }
+        }
+
+        self.elements.push((ai.to_string(), data.to_string()));
+        Ok(self)
+    }
+
+    /// Get the human-readable text, e.g. `(01)09501101530003(10)AB-123`.
+    pub fn human_readable(&self) -> String {
+        self.elements
+            .iter()
+            .map(|(ai, data)| format!("({ai}){data}"))
+            .collect()
+    }
+
+    /// Get the element string as transmitted by a barcode scanner,
+    /// with a group separator (`0x1D`) in place of each FNC1 separator.
+    pub fn to_element_string(&self) -> String {
+        let mut element_string = String::new();
+        for (i, (ai, data)) in self.elements.iter().enumerate() {
+            element_string.push_str(ai);
+            element_string.push_str(data);
+
+            if !gs1_has_predefined_length(ai) && i + 1 < self.elements.len() {
+                element_string.push('\u{1d}');
+            }
+        }
+
+        element_string
+    }
+
+    /// Encode the element string as DataMatrix ASCII codewords, starting with FNC1.
+    fn to_data_matrix_codewords(&self) -> Vec<u8> {
+        const FNC1: u8 = 232;
+
+        let mut codewords = vec![FNC1];
+        let element_string = self.to_element_string().into_bytes();
+        let mut i = 0;
+        while i < element_string.len() {
+            let c = element_string[i];
+            match element_string.get(i + 1) {
+                // Pairs of digits are encoded in a single codeword
+                Some(next) if c.is_ascii_digit() && next.is_ascii_digit() => {
+                    codewords.push(130 + (c - b'0') * 10 + (next - b'0'));
+                    i += 2;
+                    continue;
+                }
+                _ => {}
+            }
+
+            codewords.push(if c == 0x1d { FNC1 } else { c + 1 });
+            i += 1;
+        }
+
+        codewords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gs1_check_digit_computes_modulo_10() {
+        assert_eq!(gs1_check_digit("0950110153000"), Some(3));
+        assert_eq!(gs1_check_digit("400638133393"), Some(1));
+        assert_eq!(gs1_check_digit("10614141123456789"), Some(7));
+        assert_eq!(gs1_check_digit("03600029145"), Some(2));
+        assert_eq!(gs1_check_digit(""), Some(0));
+    }
+
+    #[test]
+    fn gs1_check_digit_rejects_non_digits() {
+        assert_eq!(gs1_check_digit("09501101530A0"), None);
+        assert_eq!(gs1_check_digit("-1"), None);
+    }
+
+    #[test]
+    fn gs1_add_rejects_unknown_ai() {
+        assert_eq!(
+            Gs1Element::new().add("999", "1").unwrap_err(),
+            Gs1Error::UnknownApplicationIdentifier("999".to_string())
+        );
+        assert_eq!(
+            Gs1Element::new().add("3940", "1").unwrap_err(),
+            Gs1Error::UnknownApplicationIdentifier("3940".to_string())
+        );
+    }
+
+    #[test]
+    fn gs1_add_validates_length() {
+        assert_eq!(
+            Gs1Element::new().add("01", "0950110153000").unwrap_err(),
+            Gs1Error::InvalidLength {
+                ai: "01".to_string(),
+                length: 13
+            }
+        );
+        assert_eq!(
+            Gs1Element::new().add("10", "").unwrap_err(),
+            Gs1Error::InvalidLength {
+                ai: "10".to_string(),
+                length: 0
+            }
+        );
+        assert_eq!(
+            Gs1Element::new().add("10", &"A".repeat(21)).unwrap_err(),
+            Gs1Error::InvalidLength {
+                ai: "10".to_string(),
+                length: 21
+            }
+        );
+        assert!(Gs1Element::new().add("10", &"A".repeat(20)).is_ok());
+        assert!(Gs1Element::new().add("3103", "001250").is_ok());
+        assert!(Gs1Element::new().add("3922", "1").is_ok());
+        assert!(Gs1Element::new().add("3932", "978").is_err());
+    }
+
+    #[test]
+    fn gs1_add_validates_characters() {
+        assert_eq!(
+            Gs1Element::new().add("30", "12A").unwrap_err(),
+            Gs1Error::InvalidCharacter {
+                ai: "30".to_string(),
+                character: 'A'
+            }
+        );
+        assert_eq!(
+            Gs1Element::new().add("10", "AB 123").unwrap_err(),
+            Gs1Error::InvalidCharacter {
+                ai: "10".to_string(),
+                character: ' '
+            }
+        );
+        assert!(Gs1Element::new().add("10", "AB-1/2.3_x").is_ok());
+    }
+
+    #[test]
+    fn gs1_add_validates_check_digit() {
+        assert!(Gs1Element::new().add("01", "09501101530003").is_ok());
+        assert_eq!(
+            Gs1Element::new().add("01", "09501101530004").unwrap_err(),
+            Gs1Error::InvalidCheckDigit {
+                ai: "01".to_string()
+            }
+        );
+        assert!(Gs1Element::new().add("00", "106141411234567897").is_ok());
+        assert!(Gs1Element::new().add("414", "4006381333931").is_ok());
+    }
+
+    #[test]
+    fn gs1_add_validates_date() {
+        assert!(Gs1Element::new().add("17", "251231").is_ok());
+        // Day 00 means the last day of the month
+        assert!(Gs1Element::new().add("17", "250200").is_ok());
+        assert_eq!(
+            Gs1Element::new().add("17", "251301").unwrap_err(),
+            Gs1Error::InvalidDate {
+                ai: "17".to_string()
+            }
+        );
+        assert_eq!(
+            Gs1Element::new().add("11", "250032").unwrap_err(),
+            Gs1Error::InvalidDate {
+                ai: "11".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn gs1_element_string_separates_variable_length_ais() {
+        let element = Gs1Element::new()
+            .add("01", "09501101530003")
+            .and_then(|e| e.add("10", "AB"))
+            .and_then(|e| e.add("17", "251231"))
+            .and_then(|e| e.add("21", "7"))
+            .unwrap();
+        assert_eq!(
+            element.human_readable(),
+            "(01)09501101530003(10)AB(17)251231(21)7"
+        );
+        assert_eq!(
+            element.to_element_string(),
+            "010950110153000310AB\u{1d}17251231217"
+        );
+    }
+
+    #[test]
+    fn gs1_data_matrix_codewords() {
+        let element = Gs1Element::new()
+            .add("10", "1A")
+            .and_then(|e| e.add("17", "251231"))
+            .unwrap();
+        // FNC1, "10", "1" + 1, "A" + 1, FNC1 separator, "17", "25", "12", "31"
+        assert_eq!(
+            element.to_data_matrix_codewords(),
+            [232, 140, 50, 66, 232, 147, 155, 142, 161]
+        );
+    }
+
+    #[test]
+    fn upce_to_upca_expands_last_digit_0_to_2() {
+        assert_eq!(upce_to_upca("01234505").unwrap(), "012000003455");
+        assert_eq!(upce_to_upca("04252614").unwrap(), "042100005264");
+        assert_eq!(upce_to_upca("01234529").unwrap(), "012200003459");
+    }
+
+    #[test]
+    fn upce_to_upca_expands_last_digit_3() {
+        assert_eq!(upce_to_upca("01234531").unwrap(), "012300000451");
+    }
+
+    #[test]
+    fn upce_to_upca_expands_last_digit_4() {
+        assert_eq!(upce_to_upca("01234543").unwrap(), "012340000053");
+    }
+
+    #[test]
+    fn upce_to_upca_expands_last_digit_5_to_9() {
+        assert_eq!(upce_to_upca("01234572").unwrap(), "012345000072");
+        assert_eq!(upce_to_upca("01234599").unwrap(), "012345000099");
+        assert_eq!(upce_to_upca("11234579").unwrap(), "112345000079");
+    }
+
+    #[test]
+    fn upce_to_upca_rejects_invalid_codes() {
+        assert!(matches!(
+            upce_to_upca("21234572"),
+            Err(EanError::InvalidNumberSystem(2))
+        ));
+        assert!(matches!(
+            upce_to_upca("91234572"),
+            Err(EanError::InvalidNumberSystem(9))
+        ));
+        assert!(matches!(
+            upce_to_upca("0123457"),
+            Err(EanError::InvalidLength {
+                expected: 8,
+                length: 7
+            })
+        ));
+        assert!(matches!(
+            upce_to_upca("0123457X"),
+            Err(EanError::InvalidCharacter('X'))
+        ));
+    }
+
+    #[test]
+    fn validate_code_accepts_valid_codes() {
+        assert!(BarcodeType::Ean13.validate_code("4006381333931").is_ok());
+        assert!(BarcodeType::Ean8.validate_code("96385074").is_ok());
+        assert!(BarcodeType::Upca.validate_code("042100005264").is_ok());
+        assert!(BarcodeType::Upce.validate_code("04252614").is_ok());
+        assert!(BarcodeType::Upce.validate_code("01234531").is_ok());
+        assert!(BarcodeType::Supp2.validate_code("12").is_ok());
+        assert!(BarcodeType::Supp5.validate_code("90000").is_ok());
+    }
+
+    #[test]
+    fn validate_code_rejects_bad_check_digits() {
+        assert!(matches!(
+            BarcodeType::Ean13.validate_code("4006381333932"),
+            Err(EanError::InvalidCheckDigit {
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            BarcodeType::Ean8.validate_code("96385070"),
+            Err(EanError::InvalidCheckDigit {
+                expected: 4,
+                found: 0
+            })
+        ));
+        assert!(matches!(
+            BarcodeType::Upca.validate_code("042100005260"),
+            Err(EanError::InvalidCheckDigit {
+                expected: 4,
+                found: 0
+            })
+        ));
+        // The UPC-E check digit is calculated over the expanded UPC-A code
+        assert!(matches!(
+            BarcodeType::Upce.validate_code("04252615"),
+            Err(EanError::InvalidCheckDigit {
+                expected: 4,
+                found: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn validate_code_rejects_invalid_codes() {
+        assert!(matches!(
+            BarcodeType::Ean13.validate_code("400638133393"),
+            Err(EanError::InvalidLength {
+                expected: 13,
+                length: 12
+            })
+        ));
+        assert!(matches!(
+            BarcodeType::Supp5.validate_code("9000"),
+            Err(EanError::InvalidLength {
+                expected: 5,
+                length: 4
+            })
+        ));
+        assert!(matches!(
+            BarcodeType::Ean8.validate_code("9638-074"),
+            Err(EanError::InvalidCharacter('-'))
+        ));
+        assert!(matches!(
+            BarcodeType::Upce.validate_code("24252614"),
+            Err(EanError::InvalidNumberSystem(2))
+        ));
+    }
+
+    #[test]
+    fn ean_error_display() {
+        assert_eq!(
+            EanError::InvalidLength {
+                expected: 13,
+                length: 12
+            }
+            .to_string(),
+            "Expected a code of 13 digits, got 12"
+        );
+        assert_eq!(
+            EanError::InvalidCharacter('-').to_string(),
+            "Invalid character '-' in code"
+        );
+        assert_eq!(
+            EanError::InvalidCheckDigit {
+                expected: 1,
+                found: 2
+            }
+            .to_string(),
+            "Invalid check digit 2, expected 1"
+        );
+        assert_eq!(
+            EanError::InvalidNumberSystem(2).to_string(),
+            "Invalid UPC-E number system 2"
+        );
+    }
 }
@@ -0,0 +1,1223 @@
+use crate::itext::kernel::{Color, PdfDocument, PdfFont, PdfPage, Rectangle};
+use crate::itext::layout::{
+    BlockElement, Element, ElementPropertyContainer, Paragraph, VerticalAlignment,
+};
+use crate::java_object;
+use convert_case::{Case, Casing};
+use jni::errors::Result;
+use jni::objects::{JList, JMap, JObject, JObjectArray, JString};
+use jni::sys::jsize;
+use jni::JNIEnv;
+use strum_macros::Display;
+
+java_object!(PdfAcroForm);
+java_object!(PdfFormField);
+java_object!(PdfTextFormField);
+java_object!(PdfButtonFormField);
+java_object!(PdfChoiceFormField);
+java_object!(PdfSignatureFormField);
+java_object!(PdfFormAnnotation);
+java_object!(TextFormFieldBuilder);
+java_object!(CheckBoxFormFieldBuilder);
+java_object!(RadioFormFieldBuilder);
+java_object!(ChoiceFormFieldBuilder);
+java_object!(PushButtonFormFieldBuilder);
+java_object!(SignatureFormFieldBuilder);
+java_object!(InputField);
+java_object!(TextArea);
+java_object!(CheckBox);
+java_object!(Radio);
+java_object!(ComboBoxField);
+java_object!(ListBoxField);
+java_object!(Button);
+
+/// Field flag marking a button field as a radio group.
+const FLAG_RADIO: i32 = 1 << 15;
+/// Field flag marking a button field as a push button.
+const FLAG_PUSH_BUTTON: i32 = 1 << 16;
+/// Field flag marking a choice field as a combo box.
+const FLAG_COMBO: i32 = 1 << 17;
+
+#[derive(Clone, Display)]
+pub enum CheckBoxType {
+    Check,
+    Circle,
+    Cross,
+    Diamond,
+    Square,
+    Star,
+}
+
+impl CheckBoxType {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = self.to_string().to_case(Case::Constant);
+
+        let obj = env
+            .get_static_field(
+                "com/itextpdf/forms/fields/properties/CheckBoxType",
+                field_name,
+                "Lcom/itextpdf/forms/fields/properties/CheckBoxType;",
+            )?
+            .l()?;
+        Ok(obj)
+    }
+}
+
+/// The type of a form field, see [FormFieldInfo].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormFieldType {
+    Text,
+    CheckBox,
+    RadioButton,
+    PushButton,
+    ComboBox,
+    ListBox,
+    Signature,
+    /// A field grouping other fields, without a type of its own.
+    NonTerminal,
+    /// A field type not defined by the PDF specification.
+    Unknown(String),
+}
+
+/// A form field of an existing document, see [PdfAcroForm::get_all_form_fields].
+#[derive(Debug, Clone)]
+pub struct FormFieldInfo {
+    /// The fully qualified name, with periods separating the names of parent fields.
+    pub name: String,
+    pub field_type: FormFieldType,
+    pub value: String,
+    /// The export values of a choice field, or the appearance states of a checkbox or radio group other than `Off`.
+    pub options: Vec<String>,
+    pub widgets: Vec<WidgetInfo>,
+}
+
+/// The position of a widget of a form field.
+#[derive(Debug, Clone)]
+pub struct WidgetInfo {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// The page number, starting at 1. `None` if the widget is not placed on a page.
+    pub page: Option<i32>,
+}
+
+impl<'a> PdfAcroForm<'a> {
+    /// Get the interactive form of the document. If the document has none, it is created if `create_if_not_exist` is set,
+    /// otherwise `None` is returned.
+    pub fn get_acro_form(
+        pdf_document: &PdfDocument<'a>,
+        create_if_not_exist: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Option<Self>> {
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/forms/PdfAcroForm",
+                "getAcroForm",
+                "(Lcom/itextpdf/kernel/pdf/PdfDocument;Z)Lcom/itextpdf/forms/PdfAcroForm;",
+                &[pdf_document.into(), create_if_not_exist.into()],
+            )?
+            .l()?;
+
+        if obj.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Self(obj)))
+        }
+    }
+
+    /// Add a field to the form. Its widgets are placed on the page set on the field builder.
+    pub fn add_field<F: FormField<'a>>(&self, field: &F, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "addField",
+            "(Lcom/itextpdf/forms/fields/PdfFormField;)V",
+            &[field.as_ref().into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Add a field to the form, placing its widgets on the given page.
+    pub fn add_field_to_page<F: FormField<'a>>(
+        &self,
+        field: &F,
+        page: &PdfPage<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "addField",
+            "(Lcom/itextpdf/forms/fields/PdfFormField;Lcom/itextpdf/kernel/pdf/PdfPage;)V",
+            &[field.as_ref().into(), page.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Get a field by its fully qualified name, with periods separating the names of parent fields.
+    pub fn get_field(&self, name: &str, env: &mut JNIEnv<'a>) -> Result<Option<PdfFormField<'a>>> {
+        let name = env.new_string(name)?;
+        let obj = env
+            .call_method(
+                self,
+                "getField",
+                "(Ljava/lang/String;)Lcom/itextpdf/forms/fields/PdfFormField;",
+                &[(&name).into()],
+            )?
+            .l()?;
+
+        if obj.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(PdfFormField(obj)))
+        }
+    }
+
+    /// Read all fields of the form, including the fields grouping other fields.
+    pub fn get_all_form_fields(&self, env: &mut JNIEnv<'a>) -> Result<Vec<FormFieldInfo>> {
+        let pdf_document = env
+            .call_method(
+                self,
+                "getPdfDocument",
+                "()Lcom/itextpdf/kernel/pdf/PdfDocument;",
+                &[],
+            )?
+            .l()?;
+        let fields = env
+            .call_method(self, "getAllFormFields", "()Ljava/util/Map;", &[])?
+            .l()?;
+
+        let fields = JMap::from_env(env, &fields)?;
+        let mut iter = fields.iter(env)?;
+        let mut infos = Vec::new();
+        while let Some((name, field)) = iter.next(env)? {
+            let name_j = JString::from(name);
+            let name: String = env.get_string(&name_j)?.into();
+            env.delete_local_ref(name_j)?;
+
+            let info = env.with_local_frame(16, |env| {
+                read_form_field_info(name, &field, &pdf_document, env)
+            })?;
+            infos.push(info);
+
+            env.delete_local_ref(field)?;
+        }
+
+        Ok(infos)
+    }
+
+    /// Flatten all fields, replacing them by their appearance in the page content.
+    /// If fields were selected with [PdfAcroForm::flatten_selected_fields] before, only those are flattened.
+    pub fn flatten_fields(&self, env: &mut JNIEnv<'a>) -> Result<()> {
+        env.call_method(self, "flattenFields", "()V", &[])?;
+        Ok(())
+    }
+
+    /// Flatten only the fields with the given fully qualified names. Nothing is flattened if `names` is empty.
+    ///
+    /// The selected fields are remembered by the form, so a later [PdfAcroForm::flatten_fields]
+    /// flattens only the fields selected so far instead of all fields.
+    pub fn flatten_selected_fields(&self, names: &[&str], env: &mut JNIEnv<'a>) -> Result<()> {
+        // iText flattens all fields when none are selected
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        for name in names {
+            let name = env.new_string(name)?;
+            env.call_method(
+                self,
+                "partialFormFlattening",
+                "(Ljava/lang/String;)V",
+                &[(&name).into()],
+            )?;
+        }
+
+        self.flatten_fields(env)
+    }
+
+    /// Set the default appearance string of the form, e.g. `/Helv 0 Tf 0 g`.
+    pub fn set_default_appearance(&self, appearance: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let appearance = env.new_string(appearance)?;
+        env.call_method(
+            self,
+            "setDefaultAppearance",
+            "(Ljava/lang/String;)Lcom/itextpdf/forms/PdfAcroForm;",
+            &[(&appearance).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+/// Functionality shared by all form fields.
+pub trait FormField<'a>
+where
+    Self: AsRef<JObject<'a>>,
+{
+    fn set_value(&self, value: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let value = env.new_string(value)?;
+        env.call_method(
+            self.as_ref(),
+            "setValue",
+            "(Ljava/lang/String;)Lcom/itextpdf/forms/fields/PdfFormField;",
+            &[(&value).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the font of the default appearance.
+    fn set_font(&self, font: &PdfFont<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setFont",
+            "(Lcom/itextpdf/kernel/font/PdfFont;)Lcom/itextpdf/forms/fields/AbstractPdfFormField;",
+            &[font.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the font size of the default appearance. A size of `0.0` automatically fits the text in the field.
+    fn set_font_size(&self, font_size: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setFontSize",
+            "(F)Lcom/itextpdf/forms/fields/AbstractPdfFormField;",
+            &[font_size.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the text color of the default appearance.
+    fn set_color(&self, color: &Color<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setColor",
+            "(Lcom/itextpdf/kernel/colors/Color;)Lcom/itextpdf/forms/fields/AbstractPdfFormField;",
+            &[color.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the value, regenerating the appearance with the given font and font size.
+    fn set_value_with_font(
+        &self,
+        value: &str,
+        font: &PdfFont<'a>,
+        font_size: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        self.set_font(font, env)?;
+        self.set_font_size(font_size, env)?;
+        self.set_value(value, env)
+    }
+
+    fn set_read_only(&self, read_only: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setReadOnly",
+            "(Z)Lcom/itextpdf/forms/fields/PdfFormField;",
+            &[read_only.into()],
+        )?;
+        Ok(self)
+    }
+
+    fn set_required(&self, required: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setRequired",
+            "(Z)Lcom/itextpdf/forms/fields/PdfFormField;",
+            &[required.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Regenerate the appearance of the field's widgets, e.g. after changing its font.
+    /// Returns whether the appearance was regenerated successfully.
+    fn regenerate_field(&self, env: &mut JNIEnv<'a>) -> Result<bool> {
+        env.call_method(self.as_ref(), "regenerateField", "()Z", &[])?
+            .z()
+    }
+}
+
+impl<'a> FormField<'a> for PdfFormField<'a> {}
+impl<'a> FormField<'a> for PdfTextFormField<'a> {}
+impl<'a> FormField<'a> for PdfButtonFormField<'a> {}
+impl<'a> FormField<'a> for PdfChoiceFormField<'a> {}
+impl<'a> FormField<'a> for PdfSignatureFormField<'a> {}
+
+impl<'a> PdfTextFormField<'a> {
+    /// Set the maximum number of characters in the field.
+    pub fn set_max_len(&self, max_len: i32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMaxLen",
+            "(I)Lcom/itextpdf/forms/fields/PdfTextFormField;",
+            &[max_len.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Divide the field into as many equally spaced cells as its maximum length, one character per cell.
+    pub fn set_comb(&self, comb: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setComb",
+            "(Z)Lcom/itextpdf/forms/fields/PdfTextFormField;",
+            &[comb.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_password(&self, password: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPassword",
+            "(Z)Lcom/itextpdf/forms/fields/PdfTextFormField;",
+            &[password.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> PdfButtonFormField<'a> {
+    /// Add a radio button to a radio group.
+    pub fn add_kid(&self, kid: &PdfFormAnnotation<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "addKid",
+            "(Lcom/itextpdf/forms/fields/AbstractPdfFormField;)Lcom/itextpdf/forms/fields/PdfFormField;",
+            &[kid.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> PdfChoiceFormField<'a> {
+    pub fn set_multi_select(&self, multi_select: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMultiSelect",
+            "(Z)Lcom/itextpdf/forms/fields/PdfChoiceFormField;",
+            &[multi_select.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+fn read_form_field_info(
+    name: String,
+    field: &JObject,
+    pdf_document: &JObject,
+    env: &mut JNIEnv,
+) -> Result<FormFieldInfo> {
+    let form_type = env
+        .call_method(
+            field,
+            "getFormType",
+            "()Lcom/itextpdf/kernel/pdf/PdfName;",
+            &[],
+        )?
+        .l()?;
+    let flags = env.call_method(field, "getFieldFlags", "()I", &[])?.i()?;
+
+    let field_type = if form_type.is_null() {
+        FormFieldType::NonTerminal
+    } else {
+        let form_type = env
+            .call_method(&form_type, "getValue", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let form_type: String = env.get_string(&JString::from(form_type))?.into();
+
+        match form_type.as_str() {
+            "Tx" => FormFieldType::Text,
+            "Btn" if flags & FLAG_PUSH_BUTTON != 0 => FormFieldType::PushButton,
+            "Btn" if flags & FLAG_RADIO != 0 => FormFieldType::RadioButton,
+            "Btn" => FormFieldType::CheckBox,
+            "Ch" if flags & FLAG_COMBO != 0 => FormFieldType::ComboBox,
+            "Ch" => FormFieldType::ListBox,
+            "Sig" => FormFieldType::Signature,
+            _ => FormFieldType::Unknown(form_type),
+        }
+    };
+
+    let value = env
+        .call_method(field, "getValueAsString", "()Ljava/lang/String;", &[])?
+        .l()?;
+    let value: String = env.get_string(&JString::from(value))?.into();
+
+    let options = match field_type {
+        FormFieldType::CheckBox | FormFieldType::RadioButton => {
+            let states = env
+                .call_method(field, "getAppearanceStates", "()[Ljava/lang/String;", &[])?
+                .l()?;
+            let states = JObjectArray::from(states);
+
+            let mut options = Vec::new();
+            for i in 0..env.get_array_length(&states)? {
+                let state = JString::from(env.get_object_array_element(&states, i)?);
+                let state: String = env.get_string(&state)?.into();
+                if state != "Off" {
+                    options.push(state);
+                }
+            }
+            options
+        }
+        FormFieldType::ComboBox | FormFieldType::ListBox => read_choice_options(field, env)?,
+        _ => Vec::new(),
+    };
+
+    let widgets = env
+        .call_method(field, "getWidgets", "()Ljava/util/List;", &[])?
+        .l()?;
+    let widgets = JList::from_env(env, &widgets)?;
+    let mut widget_infos = Vec::new();
+    for i in 0..widgets.size(env)? {
+        if let Some(widget) = widgets.get(env, i)? {
+            widget_infos.push(read_widget_info(&widget, pdf_document, env)?);
+        }
+    }
+
+    Ok(FormFieldInfo {
+        name,
+        field_type,
+        value,
+        options,
+        widgets: widget_infos,
+    })
+}
+
+/// Read the export values of a choice field. Options are either a text string,
+/// or an array of the export value and the displayed text.
+fn read_choice_options(field: &JObject, env: &mut JNIEnv) -> Result<Vec<String>> {
+    let options = env
+        .call_method(
+            field,
+            "getOptions",
+            "()Lcom/itextpdf/kernel/pdf/PdfArray;",
+            &[],
+        )?
+        .l()?;
+    if options.is_null() {
+        return Ok(Vec::new());
+    }
+
+    let mut values = Vec::new();
+    for i in 0..env.call_method(&options, "size", "()I", &[])?.i()? {
+        let option = env
+            .call_method(
+                &options,
+                "get",
+                "(I)Lcom/itextpdf/kernel/pdf/PdfObject;",
+                &[i.into()],
+            )?
+            .l()?;
+
+        let option = if env.is_instance_of(&option, "com/itextpdf/kernel/pdf/PdfArray")? {
+            env.call_method(
+                &option,
+                "getAsString",
+                "(I)Lcom/itextpdf/kernel/pdf/PdfString;",
+                &[0.into()],
+            )?
+            .l()?
+        } else {
+            option
+        };
+        if !env.is_instance_of(&option, "com/itextpdf/kernel/pdf/PdfString")? {
+            continue;
+        }
+
+        let value = env
+            .call_method(&option, "toUnicodeString", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let value: String = env.get_string(&JString::from(value))?.into();
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+fn read_widget_info(
+    widget: &JObject,
+    pdf_document: &JObject,
+    env: &mut JNIEnv,
+) -> Result<WidgetInfo> {
+    let rectangle = env
+        .call_method(
+            widget,
+            "getRectangle",
+            "()Lcom/itextpdf/kernel/pdf/PdfArray;",
+            &[],
+        )?
+        .l()?;
+    let rectangle = env
+        .call_method(
+            &rectangle,
+            "toRectangle",
+            "()Lcom/itextpdf/kernel/geom/Rectangle;",
+            &[],
+        )?
+        .l()?;
+
+    let x = env.call_method(&rectangle, "getX", "()F", &[])?.f()?;
+    let y = env.call_method(&rectangle, "getY", "()F", &[])?.f()?;
+    let width = env.call_method(&rectangle, "getWidth", "()F", &[])?.f()?;
+    let height = env.call_method(&rectangle, "getHeight", "()F", &[])?.f()?;
+
+    Ok(WidgetInfo {
+        x,
+        y,
+        width,
+        height,
+        page: find_widget_page(widget, pdf_document, env)?,
+    })
+}
+
+/// Find the page number of a widget. Widgets often lack a reference to their page,
+/// in which case the pages are searched for the widget.
+fn find_widget_page(
+    widget: &JObject,
+    pdf_document: &JObject,
+    env: &mut JNIEnv,
+) -> Result<Option<i32>> {
+    let page = env
+        .call_method(
+            widget,
+            "getPage",
+            "()Lcom/itextpdf/kernel/pdf/PdfPage;",
+            &[],
+        )?
+        .l()?;
+    if !page.is_null() {
+        let page_number = env
+            .call_method(
+                pdf_document,
+                "getPageNumber",
+                "(Lcom/itextpdf/kernel/pdf/PdfPage;)I",
+                &[(&page).into()],
+            )?
+            .i()?;
+        return Ok((page_number > 0).then_some(page_number));
+    }
+
+    let number_of_pages = env
+        .call_method(pdf_document, "getNumberOfPages", "()I", &[])?
+        .i()?;
+    for page_number in 1..=number_of_pages {
+        let page = env
+            .call_method(
+                pdf_document,
+                "getPage",
+                "(I)Lcom/itextpdf/kernel/pdf/PdfPage;",
+                &[page_number.into()],
+            )?
+            .l()?;
+        let contains = env
+            .call_method(
+                &page,
+                "containsAnnotation",
+                "(Lcom/itextpdf/kernel/pdf/annot/PdfAnnotation;)Z",
+                &[widget.into()],
+            )?
+            .z()?;
+        env.delete_local_ref(page)?;
+
+        if contains {
+            return Ok(Some(page_number));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Functionality shared by all builders of form fields with widgets.
+pub trait FormFieldBuilder<'a>
+where
+    Self: AsRef<JObject<'a>>,
+{
+    /// Set the position and size of the field's widget on the page.
+    fn set_widget_rectangle(
+        &self,
+        rectangle: &Rectangle<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setWidgetRectangle",
+            "(Lcom/itextpdf/kernel/geom/Rectangle;)Lcom/itextpdf/forms/fields/TerminalFormFieldBuilder;",
+            &[rectangle.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the page the field's widget is placed on.
+    fn set_page(&self, page: &PdfPage<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setPage",
+            "(Lcom/itextpdf/kernel/pdf/PdfPage;)Lcom/itextpdf/forms/fields/TerminalFormFieldBuilder;",
+            &[page.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the font used for the field's appearance.
+    fn set_font(&self, font: &PdfFont<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setFont",
+            "(Lcom/itextpdf/kernel/font/PdfFont;)Lcom/itextpdf/forms/fields/TerminalFormFieldBuilder;",
+            &[font.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+macro_rules! form_field_builder_constructor {
+    ($name:ident, $class:expr) => {
+        impl<'a> FormFieldBuilder<'a> for $name<'a> {}
+
+        impl<'a> $name<'a> {
+            /// Create a builder for a field with the given name. Periods in the name separate the names of parent fields.
+            pub fn new(
+                pdf_document: &PdfDocument<'a>,
+                name: &str,
+                env: &mut JNIEnv<'a>,
+            ) -> Result<Self> {
+                let name = env.new_string(name)?;
+                let obj = env.new_object(
+                    $class,
+                    "(Lcom/itextpdf/kernel/pdf/PdfDocument;Ljava/lang/String;)V",
+                    &[pdf_document.into(), (&name).into()],
+                )?;
+                Ok(Self(obj))
+            }
+        }
+    };
+}
+
+form_field_builder_constructor!(
+    TextFormFieldBuilder,
+    "com/itextpdf/forms/fields/TextFormFieldBuilder"
+);
+form_field_builder_constructor!(
+    CheckBoxFormFieldBuilder,
+    "com/itextpdf/forms/fields/CheckBoxFormFieldBuilder"
+);
+form_field_builder_constructor!(
+    RadioFormFieldBuilder,
+    "com/itextpdf/forms/fields/RadioFormFieldBuilder"
+);
+form_field_builder_constructor!(
+    ChoiceFormFieldBuilder,
+    "com/itextpdf/forms/fields/ChoiceFormFieldBuilder"
+);
+form_field_builder_constructor!(
+    PushButtonFormFieldBuilder,
+    "com/itextpdf/forms/fields/PushButtonFormFieldBuilder"
+);
+form_field_builder_constructor!(
+    SignatureFormFieldBuilder,
+    "com/itextpdf/forms/fields/SignatureFormFieldBuilder"
+);
+
+impl<'a> TextFormFieldBuilder<'a> {
+    pub fn create_text(&self, env: &mut JNIEnv<'a>) -> Result<PdfTextFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createText",
+                "()Lcom/itextpdf/forms/fields/PdfTextFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfTextFormField(obj))
+    }
+
+    pub fn create_multiline_text(&self, env: &mut JNIEnv<'a>) -> Result<PdfTextFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createMultilineText",
+                "()Lcom/itextpdf/forms/fields/PdfTextFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfTextFormField(obj))
+    }
+}
+
+impl<'a> CheckBoxFormFieldBuilder<'a> {
+    /// Set the symbol shown when the checkbox is checked.
+    pub fn set_check_type(&self, check_type: CheckBoxType, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let check_type_j = check_type.get_java_value(env)?;
+        env.call_method(
+            self,
+            "setCheckType",
+            "(Lcom/itextpdf/forms/fields/properties/CheckBoxType;)Lcom/itextpdf/forms/fields/CheckBoxFormFieldBuilder;",
+            &[(&check_type_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Create the checkbox. To check it, set its value to `"Yes"`.
+    pub fn create_check_box(&self, env: &mut JNIEnv<'a>) -> Result<PdfButtonFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createCheckBox",
+                "()Lcom/itextpdf/forms/fields/PdfButtonFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfButtonFormField(obj))
+    }
+}
+
+impl<'a> RadioFormFieldBuilder<'a> {
+    /// Create the radio group. Add buttons created with [RadioFormFieldBuilder::create_radio_button] using [PdfButtonFormField::add_kid],
+    /// and select one by setting the group's value to its appearance name.
+    pub fn create_radio_group(&self, env: &mut JNIEnv<'a>) -> Result<PdfButtonFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createRadioGroup",
+                "()Lcom/itextpdf/forms/fields/PdfButtonFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfButtonFormField(obj))
+    }
+
+    /// Create a button of the radio group, with the appearance name identifying it as the value of the group.
+    pub fn create_radio_button(
+        &self,
+        appearance_name: &str,
+        rectangle: &Rectangle<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<PdfFormAnnotation<'a>> {
+        let appearance_name = env.new_string(appearance_name)?;
+        let obj = env
+            .call_method(
+                self,
+                "createRadioButton",
+                "(Ljava/lang/String;Lcom/itextpdf/kernel/geom/Rectangle;)Lcom/itextpdf/forms/fields/PdfFormAnnotation;",
+                &[(&appearance_name).into(), rectangle.into()],
+            )?
+            .l()?;
+        Ok(PdfFormAnnotation(obj))
+    }
+}
+
+impl<'a> ChoiceFormFieldBuilder<'a> {
+    pub fn set_options(&self, options: &[&str], env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let array =
+            env.new_object_array(options.len() as jsize, "java/lang/String", JObject::null())?;
+        for (i, option) in options.iter().enumerate() {
+            let option = env.new_string(option)?;
+            env.set_object_array_element(&array, i as jsize, option)?;
+        }
+
+        env.call_method(
+            self,
+            "setOptions",
+            "([Ljava/lang/String;)Lcom/itextpdf/forms/fields/ChoiceFormFieldBuilder;",
+            &[(&array).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn create_combo_box(&self, env: &mut JNIEnv<'a>) -> Result<PdfChoiceFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createComboBox",
+                "()Lcom/itextpdf/forms/fields/PdfChoiceFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfChoiceFormField(obj))
+    }
+
+    pub fn create_list(&self, env: &mut JNIEnv<'a>) -> Result<PdfChoiceFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createList",
+                "()Lcom/itextpdf/forms/fields/PdfChoiceFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfChoiceFormField(obj))
+    }
+}
+
+impl<'a> PushButtonFormFieldBuilder<'a> {
+    pub fn set_caption(&self, caption: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let caption = env.new_string(caption)?;
+        env.call_method(
+            self,
+            "setCaption",
+            "(Ljava/lang/String;)Lcom/itextpdf/forms/fields/PushButtonFormFieldBuilder;",
+            &[(&caption).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn create_push_button(&self, env: &mut JNIEnv<'a>) -> Result<PdfButtonFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createPushButton",
+                "()Lcom/itextpdf/forms/fields/PdfButtonFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfButtonFormField(obj))
+    }
+}
+
+impl<'a> SignatureFormFieldBuilder<'a> {
+    /// Create an empty signature field, to be signed later.
+    pub fn create_signature(&self, env: &mut JNIEnv<'a>) -> Result<PdfSignatureFormField<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "createSignature",
+                "()Lcom/itextpdf/forms/fields/PdfSignatureFormField;",
+                &[],
+            )?
+            .l()?;
+        Ok(PdfSignatureFormField(obj))
+    }
+}
+
+/// Functionality shared by all form elements in the layout tree.
+///
+/// Form elements are flattened into the page content by default. Use [LayoutFormField::set_interactive]
+/// to create AcroForm fields instead.
+pub trait LayoutFormField<'a>
+where
+    Self: AsRef<JObject<'a>>,
+{
+    fn set_value(&self, value: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let value = env.new_string(value)?;
+        env.call_method(
+            self.as_ref(),
+            "setValue",
+            "(Ljava/lang/String;)Lcom/itextpdf/forms/form/element/IFormField;",
+            &[(&value).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set whether the element becomes an interactive AcroForm field, instead of being flattened.
+    fn set_interactive(&self, interactive: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setInteractive",
+            "(Z)Lcom/itextpdf/forms/form/element/IFormField;",
+            &[interactive.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+/// Form elements are no `BlockElement` on the Java side, so the block properties are set directly.
+macro_rules! layout_form_field {
+    ($name:ident) => {
+        impl<'a> LayoutFormField<'a> for $name<'a> {}
+        impl<'a> ElementPropertyContainer<'a> for $name<'a> {}
+        impl<'a> Element<'a> for $name<'a> {}
+
+        impl<'a> BlockElement<'a> for $name<'a> {
+            fn set_width(&self, width: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+                set_point_value_property(self.as_ref(), "WIDTH", width, env)?;
+                Ok(self)
+            }
+
+            fn set_height(&self, height: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+                set_point_value_property(self.as_ref(), "HEIGHT", height, env)?;
+                Ok(self)
+            }
+
+            fn set_margin_bottom(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+                set_point_value_property(self.as_ref(), "MARGIN_BOTTOM", margin, env)?;
+                Ok(self)
+            }
+
+            fn set_margin_top(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+                set_point_value_property(self.as_ref(), "MARGIN_TOP", margin, env)?;
+                Ok(self)
+            }
+
+            fn set_margin_left(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+                set_point_value_property(self.as_ref(), "MARGIN_LEFT", margin, env)?;
+                Ok(self)
+            }
+
+            fn set_margin_right(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+                set_point_value_property(self.as_ref(), "MARGIN_RIGHT", margin, env)?;
+                Ok(self)
+            }
+
+            fn set_vertical_alignment(
+                &self,
+                alignment: VerticalAlignment,
+                env: &mut JNIEnv<'a>,
+            ) -> Result<&Self> {
+                let valign_j = alignment.get_java_value(env)?;
+                set_property(self.as_ref(), "VERTICAL_ALIGNMENT", &valign_j, env)?;
+                Ok(self)
+            }
+        }
+    };
+}
+
+layout_form_field!(InputField);
+layout_form_field!(TextArea);
+layout_form_field!(CheckBox);
+layout_form_field!(Radio);
+layout_form_field!(ComboBoxField);
+layout_form_field!(ListBoxField);
+layout_form_field!(Button);
+
+fn set_property<'a>(
+    element: &JObject<'a>,
+    property_name: &str,
+    value: &JObject<'a>,
+    env: &mut JNIEnv<'a>,
+) -> Result<()> {
+    let property = env
+        .get_static_field(
+            "com/itextpdf/layout/properties/Property",
+            property_name,
+            "I",
+        )?
+        .i()?;
+    env.call_method(
+        element,
+        "setProperty",
+        "(ILjava/lang/Object;)V",
+        &[property.into(), value.into()],
+    )?;
+    Ok(())
+}
+
+fn set_point_value_property<'a>(
+    element: &JObject<'a>,
+    property_name: &str,
+    value: f32,
+    env: &mut JNIEnv<'a>,
+) -> Result<()> {
+    let value = env
+        .call_static_method(
+            "com/itextpdf/layout/properties/UnitValue",
+            "createPointValue",
+            "(F)Lcom/itextpdf/layout/properties/UnitValue;",
+            &[value.into()],
+        )?
+        .l()?;
+    set_property(element, property_name, &value, env)
+}
+
+/// Create a form element of the given class, identified by the name of its field.
+fn new_layout_form_field<'a>(class: &str, id: &str, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+    let id = env.new_string(id)?;
+    env.new_object(class, "(Ljava/lang/String;)V", &[(&id).into()])
+}
+
+impl<'a> InputField<'a> {
+    pub fn new(id: &str, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = new_layout_form_field("com/itextpdf/forms/form/element/InputField", id, env)?;
+        Ok(Self(obj))
+    }
+
+    /// Set the paragraph shown while the field is empty.
+    pub fn set_placeholder(
+        &self,
+        placeholder: &Paragraph<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPlaceholder",
+            "(Lcom/itextpdf/layout/element/Paragraph;)V",
+            &[placeholder.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> TextArea<'a> {
+    pub fn new(id: &str, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = new_layout_form_field("com/itextpdf/forms/form/element/TextArea", id, env)?;
+        Ok(Self(obj))
+    }
+
+    /// Set the paragraph shown while the field is empty.
+    pub fn set_placeholder(
+        &self,
+        placeholder: &Paragraph<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPlaceholder",
+            "(Lcom/itextpdf/layout/element/Paragraph;)V",
+            &[placeholder.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> CheckBox<'a> {
+    pub fn new(id: &str, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = new_layout_form_field("com/itextpdf/forms/form/element/CheckBox", id, env)?;
+        Ok(Self(obj))
+    }
+
+    pub fn set_checked(&self, checked: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setChecked",
+            "(Z)Lcom/itextpdf/forms/form/element/CheckBox;",
+            &[checked.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the symbol shown when the checkbox is checked.
+    pub fn set_check_box_type(
+        &self,
+        check_type: CheckBoxType,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let check_type_j = check_type.get_java_value(env)?;
+        env.call_method(
+            self,
+            "setCheckBoxType",
+            "(Lcom/itextpdf/forms/fields/properties/CheckBoxType;)Lcom/itextpdf/forms/form/element/CheckBox;",
+            &[(&check_type_j).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> Radio<'a> {
+    /// Create a radio button. Radio buttons with the same group name form a single field,
+    /// with the value of the selected button.
+    pub fn new(id: &str, group_name: &str, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let id = env.new_string(id)?;
+        let group_name = env.new_string(group_name)?;
+        let obj = env.new_object(
+            "com/itextpdf/forms/form/element/Radio",
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+            &[(&id).into(), (&group_name).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn set_checked(&self, checked: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setChecked",
+            "(Z)Lcom/itextpdf/forms/form/element/Radio;",
+            &[checked.into()],
+        )?;
+        Ok(self)
+    }
+}
+
+/// Create a `SelectFieldItem`, with the displayed text defaulting to the value.
+fn new_select_field_item<'a>(
+    value: &str,
+    display_value: Option<&str>,
+    env: &mut JNIEnv<'a>,
+) -> Result<JObject<'a>> {
+    let display_value = env.new_string(display_value.unwrap_or(value))?;
+    let value = env.new_string(value)?;
+    env.new_object(
+        "com/itextpdf/forms/form/element/SelectFieldItem",
+        "(Ljava/lang/String;Ljava/lang/String;)V",
+        &[(&value).into(), (&display_value).into()],
+    )
+}
+
+impl<'a> ComboBoxField<'a> {
+    pub fn new(id: &str, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = new_layout_form_field("com/itextpdf/forms/form/element/ComboBoxField", id, env)?;
+        Ok(Self(obj))
+    }
+
+    /// Add an option with the given export value, and optionally a different displayed text.
+    pub fn add_option(
+        &self,
+        value: &str,
+        display_value: Option<&str>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let item = new_select_field_item(value, display_value, env)?;
+        env.call_method(
+            self,
+            "addOption",
+            "(Lcom/itextpdf/forms/form/element/SelectFieldItem;)V",
+            &[(&item).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Select the option with the given export value.
+    pub fn set_selected(&self, value: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let value = env.new_string(value)?;
+        env.call_method(
+            self,
+            "setSelected",
+            "(Ljava/lang/String;)Lcom/itextpdf/forms/form/element/ComboBoxField;",
+            &[(&value).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> ListBoxField<'a> {
+    /// Create a list box showing `size` options at once.
+    pub fn new(
+        id: &str,
+        size: i32,
+        allow_multiple_selection: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let id = env.new_string(id)?;
+        let obj = env.new_object(
+            "com/itextpdf/forms/form/element/ListBoxField",
+            "(Ljava/lang/String;IZ)V",
+            &[(&id).into(), size.into(), allow_multiple_selection.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Add an option with the given export value, and optionally a different displayed text.
+    pub fn add_option(
+        &self,
+        value: &str,
+        display_value: Option<&str>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let item = new_select_field_item(value, display_value, env)?;
+        env.call_method(
+            self,
+            "addOption",
+            "(Lcom/itextpdf/forms/form/element/SelectFieldItem;)V",
+            &[(&item).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> Button<'a> {
+    /// Create a push button. Its caption is set with [LayoutFormField::set_value].
+    pub fn new(id: &str, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = new_layout_form_field("com/itextpdf/forms/form/element/Button", id, env)?;
+        Ok(Self(obj))
+    }
+}
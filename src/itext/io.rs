@@ -1,12 +1,16 @@
+use crate::java::{AwtColor, BufferedImage};
 use crate::java_object;
 use convert_case::{Case, Casing};
 use jni::errors::Result;
-use jni::objects::JObject;
+use jni::objects::{JByteArray, JIntArray, JObject, JObjectArray, JString};
 use jni::sys::{jbyte, jsize};
 use jni::JNIEnv;
+use std::fmt;
+use std::path::Path;
 use strum_macros::Display;
 
 java_object!(ImageData);
+java_object!(ImageDataFactory);
 java_object!(FontProgram);
 java_object!(FontProgramFactory);
 
@@ -37,6 +41,441 @@ impl<'a> ImageData<'a> {
         let obj = env.call_method(self, "getHeight", "()F", &[])?.f()?;
         Ok(obj)
     }
+
+    /// Get the format the image was loaded from.
+    pub fn get_original_type(&self, env: &mut JNIEnv<'a>) -> Result<ImageType> {
+        let obj = env
+            .call_method(
+                self,
+                "getOriginalType",
+                "()Lcom/itextpdf/io/image/ImageType;",
+                &[],
+            )?
+            .l()?;
+        let name = env
+            .call_method(&obj, "name", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let name: String = env.get_string(&name.into())?.into();
+        Ok(ImageType::from_java_name(&name))
+    }
+
+    /// Get the horizontal resolution in dots per inch, or 0 if unknown.
+    pub fn get_dpi_x(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "getDpiX", "()I", &[])?.i()
+    }
+
+    /// Get the vertical resolution in dots per inch, or 0 if unknown.
+    pub fn get_dpi_y(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "getDpiY", "()I", &[])?.i()
+    }
+
+    /// Get the number of bits per color component.
+    pub fn get_bpc(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "getBpc", "()I", &[])?.i()
+    }
+
+    /// Get the number of color components, e.g. 1 for grayscale, 3 for RGB and 4 for CMYK.
+    pub fn get_color_encoding_components_number(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "getColorEncodingComponentsNumber", "()I", &[])?
+            .i()
+    }
+
+    /// Get the rotation of the image in radians.
+    pub fn get_rotation(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getRotation", "()F", &[])?.f()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum ImageType {
+    Jpeg,
+    Png,
+    Gif,
+    Bmp,
+    Tiff,
+    Wmf,
+    Ps,
+    Jpeg2000,
+    Jbig2,
+    Raw,
+    None,
+}
+
+impl ImageType {
+    fn from_java_name(name: &str) -> Self {
+        match name {
+            "JPEG" => Self::Jpeg,
+            "PNG" => Self::Png,
+            "GIF" => Self::Gif,
+            "BMP" => Self::Bmp,
+            "TIFF" => Self::Tiff,
+            "WMF" => Self::Wmf,
+            "PS" => Self::Ps,
+            "JPEG2000" => Self::Jpeg2000,
+            "JBIG2" => Self::Jbig2,
+            "RAW" => Self::Raw,
+            _ => Self::None,
+        }
+    }
+}
+
+/// The layout of raw pixel data, with 8 bits per component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawPixelFormat {
+    Gray,
+    Rgb,
+    Rgba,
+}
+
+impl RawPixelFormat {
+    fn components(&self) -> usize {
+        match self {
+            Self::Gray => 1,
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PixelDataError {
+    /// The dimensions are negative, or too large to address the pixel data.
+    InvalidDimensions {
+        width: i32,
+        height: i32,
+    },
+    /// The length of the pixel data does not match the dimensions and pixel format.
+    InvalidLength {
+        expected: usize,
+        length: usize,
+    },
+    Jni(jni::errors::Error),
+}
+
+impl fmt::Display for PixelDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDimensions { width, height } => {
+                write!(f, "Invalid image dimensions {width}x{height}")
+            }
+            Self::InvalidLength { expected, length } => {
+                write!(f, "Expected {expected} bytes of pixel data, got {length}")
+            }
+            Self::Jni(e) => write!(f, "JNI error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PixelDataError {}
+
+impl From<jni::errors::Error> for PixelDataError {
+    fn from(value: jni::errors::Error) -> Self {
+        Self::Jni(value)
+    }
+}
+
+impl<'a> ImageDataFactory<'a> {
+    /// Load an image from a file, detecting its format.
+    pub fn create_from_path(path: &Path, env: &mut JNIEnv<'a>) -> Result<ImageData<'a>> {
+        let filename = env.new_string(path.to_string_lossy())?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "create",
+                "(Ljava/lang/String;)Lcom/itextpdf/io/image/ImageData;",
+                &[(&filename).into()],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    pub fn create_png(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<ImageData<'a>> {
+        Self::create_with_format("createPng", bytes, env)
+    }
+
+    pub fn create_jpeg(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<ImageData<'a>> {
+        Self::create_with_format("createJpeg", bytes, env)
+    }
+
+    /// Load a single page of a TIFF image. Pages start at 1.
+    pub fn create_tiff(bytes: &[u8], page: i32, env: &mut JNIEnv<'a>) -> Result<ImageData<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "createTiff",
+                "([BZIZ)Lcom/itextpdf/io/image/ImageData;",
+                &[
+                    (&byte_array).into(),
+                    false.into(),
+                    page.into(),
+                    false.into(),
+                ],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    /// Get the number of pages of a TIFF image.
+    pub fn get_tiff_number_of_pages(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<i32> {
+        let byte_array = new_byte_array(bytes, env)?;
+        env.call_static_method(
+            "com/itextpdf/io/image/TiffImageData",
+            "getNumberOfPages",
+            "([B)I",
+            &[(&byte_array).into()],
+        )?
+        .i()
+    }
+
+    /// Load every page of a, possibly multi-page, TIFF image.
+    pub fn create_tiff_pages(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<Vec<ImageData<'a>>> {
+        let number_of_pages = Self::get_tiff_number_of_pages(bytes, env)?;
+        (1..=number_of_pages)
+            .map(|page| Self::create_tiff(bytes, page, env))
+            .collect()
+    }
+
+    /// Load every frame of a, possibly animated, GIF image.
+    pub fn create_gif_frames(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<Vec<ImageData<'a>>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let list = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "createGifFrames",
+                "([B)Ljava/util/List;",
+                &[(&byte_array).into()],
+            )?
+            .l()?;
+
+        let size = env.call_method(&list, "size", "()I", &[])?.i()?;
+        (0..size)
+            .map(|i| {
+                let obj = env
+                    .call_method(&list, "get", "(I)Ljava/lang/Object;", &[i.into()])?
+                    .l()?;
+                Ok(ImageData(obj))
+            })
+            .collect()
+    }
+
+    /// Load a single frame of a GIF image. Frames start at 1.
+    pub fn create_gif_frame(
+        bytes: &[u8],
+        frame: i32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<ImageData<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "createGifFrame",
+                "([BI)Lcom/itextpdf/io/image/ImageData;",
+                &[(&byte_array).into(), frame.into()],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    /// Load a single page of a JBIG2 image. Pages start at 1.
+    pub fn create_jbig2(bytes: &[u8], page: i32, env: &mut JNIEnv<'a>) -> Result<ImageData<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "createJbig2",
+                "([BI)Lcom/itextpdf/io/image/ImageData;",
+                &[(&byte_array).into(), page.into()],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    pub fn create_bmp(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<ImageData<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "createBmp",
+                "([BZ)Lcom/itextpdf/io/image/ImageData;",
+                &[(&byte_array).into(), false.into()],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    /// Create an image from raw pixel data with 8 bits per component, stored row by row from the top left.
+    /// For [RawPixelFormat::Rgba], the alpha channel is added as a soft mask.
+    ///
+    /// The dimensions must not be negative, and the length of `data` must match the dimensions and pixel format.
+    pub fn create_from_pixels(
+        width: i32,
+        height: i32,
+        format: RawPixelFormat,
+        data: &[u8],
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<ImageData<'a>, PixelDataError> {
+        if width < 0 || height < 0 {
+            return Err(PixelDataError::InvalidDimensions { width, height });
+        }
+
+        let expected = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|pixels| pixels.checked_mul(format.components()))
+            .ok_or(PixelDataError::InvalidDimensions { width, height })?;
+        if expected != data.len() {
+            return Err(PixelDataError::InvalidLength {
+                expected,
+                length: data.len(),
+            });
+        }
+
+        match format {
+            RawPixelFormat::Gray => Ok(Self::create_raw(width, height, 1, 8, data, None, env)?),
+            RawPixelFormat::Rgb => Ok(Self::create_raw(width, height, 3, 8, data, None, env)?),
+            RawPixelFormat::Rgba => {
+                let (rgb, alpha): (Vec<_>, Vec<_>) = data
+                    .chunks_exact(4)
+                    .map(|pixel| ([pixel[0], pixel[1], pixel[2]], pixel[3]))
+                    .unzip();
+                let rgb = rgb.concat();
+
+                let image = Self::create_raw(width, height, 3, 8, &rgb, None, env)?;
+                let mask = Self::create_raw(width, height, 1, 8, &alpha, None, env)?;
+                env.call_method(&mask, "makeMask", "()V", &[])?;
+                env.call_method(
+                    &image,
+                    "setImageMask",
+                    "(Lcom/itextpdf/io/image/ImageData;)V",
+                    &[(&mask).into()],
+                )?;
+
+                Ok(image)
+            }
+        }
+    }
+
+    /// Create an image from raw, uncompressed data.
+    /// `components` is the number of color components per pixel and `bpc` the number of bits per component.
+    /// `transparency` optionally lists, per component, the range of color values to mask out,
+    /// as pairs of minimum and maximum.
+    pub fn create_raw(
+        width: i32,
+        height: i32,
+        components: i32,
+        bpc: i32,
+        data: &[u8],
+        transparency: Option<&[i32]>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<ImageData<'a>> {
+        let byte_array = new_byte_array(data, env)?;
+        let transparency = match transparency {
+            Some(transparency) => {
+                let array = env.new_int_array(transparency.len() as jsize)?;
+                env.set_int_array_region(&array, 0, transparency)?;
+                JObject::from(array)
+            }
+            None => JObject::null(),
+        };
+
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "create",
+                "(IIII[B[I)Lcom/itextpdf/io/image/ImageData;",
+                &[
+                    width.into(),
+                    height.into(),
+                    components.into(),
+                    bpc.into(),
+                    (&byte_array).into(),
+                    (&transparency).into(),
+                ],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    /// Create an image from an image decoded by the `image` crate.
+    #[cfg(feature = "image")]
+    pub fn create_from_dynamic_image(
+        image: &image::DynamicImage,
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<ImageData<'a>, PixelDataError> {
+        let width = i32::try_from(image.width()).unwrap_or(-1);
+        let height = i32::try_from(image.height()).unwrap_or(-1);
+        let color = image.color();
+
+        if color.has_alpha() {
+            let pixels = image.to_rgba8();
+            Self::create_from_pixels(width, height, RawPixelFormat::Rgba, &pixels, env)
+        } else if color.has_color() {
+            let pixels = image.to_rgb8();
+            Self::create_from_pixels(width, height, RawPixelFormat::Rgb, &pixels, env)
+        } else {
+            let pixels = image.to_luma8();
+            Self::create_from_pixels(width, height, RawPixelFormat::Gray, &pixels, env)
+        }
+    }
+
+    /// Create an image from a `BufferedImage`.
+    /// If `background` is set, transparent pixels are replaced by it.
+    pub fn create_from_buffered_image(
+        image: &BufferedImage<'a>,
+        background: Option<&AwtColor<'a>>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<ImageData<'a>> {
+        let null = JObject::null();
+        let background = background.map(AsRef::as_ref).unwrap_or(&null);
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                "create",
+                "(Ljava/awt/Image;Ljava/awt/Color;)Lcom/itextpdf/io/image/ImageData;",
+                &[image.into(), background.into()],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+
+    /// Check whether the bytes are an image in a format supported by iText.
+    pub fn is_supported_type(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<bool> {
+        let byte_array = new_byte_array(bytes, env)?;
+        env.call_static_method(
+            "com/itextpdf/io/image/ImageDataFactory",
+            "isSupportedType",
+            "([B)Z",
+            &[(&byte_array).into()],
+        )?
+        .z()
+    }
+
+    fn create_with_format(
+        method: &str,
+        bytes: &[u8],
+        env: &mut JNIEnv<'a>,
+    ) -> Result<ImageData<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let obj = env
+            .call_static_method(
+                "com/itextpdf/io/image/ImageDataFactory",
+                method,
+                "([B)Lcom/itextpdf/io/image/ImageData;",
+                &[(&byte_array).into()],
+            )?
+            .l()?;
+        Ok(ImageData(obj))
+    }
+}
+
+pub(crate) fn new_byte_array<'a>(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<JByteArray<'a>> {
+    let byte_array = env.new_byte_array(bytes.len() as jsize)?;
+    env.set_byte_array_region(
+        &byte_array,
+        0,
+        &bytes.iter().map(|byte| *byte as jbyte).collect::<Vec<_>>(),
+    )?;
+    Ok(byte_array)
 }
 
 #[derive(Debug, Clone, Display)]
@@ -69,6 +508,94 @@ impl StandardFont {
     }
 }
 
+impl<'a> FontProgram<'a> {
+    fn get_font_names(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        env.call_method(
+            self,
+            "getFontNames",
+            "()Lcom/itextpdf/io/font/FontNames;",
+            &[],
+        )?
+        .l()
+    }
+
+    fn get_font_metrics(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        env.call_method(
+            self,
+            "getFontMetrics",
+            "()Lcom/itextpdf/io/font/FontMetrics;",
+            &[],
+        )?
+        .l()
+    }
+
+    /// Get the PostScript name of the font.
+    pub fn get_font_name(&self, env: &mut JNIEnv<'a>) -> Result<String> {
+        let names = self.get_font_names(env)?;
+        let name: JString = env
+            .call_method(&names, "getFontName", "()Ljava/lang/String;", &[])?
+            .l()?
+            .into();
+        let name = env.get_string(&name)?.into();
+        Ok(name)
+    }
+
+    /// Get the family name of the font, if the font specifies one.
+    pub fn get_family_name(&self, env: &mut JNIEnv<'a>) -> Result<Option<String>> {
+        let names = self.get_font_names(env)?;
+        // Each entry is [platform id, encoding id, language id, name]
+        let family_names: JObjectArray = env
+            .call_method(&names, "getFamilyName", "()[[Ljava/lang/String;", &[])?
+            .l()?
+            .into();
+        if family_names.is_null() || env.get_array_length(&family_names)? == 0 {
+            return Ok(None);
+        }
+
+        let entry: JObjectArray = env.get_object_array_element(&family_names, 0)?.into();
+        let name: JString = env.get_object_array_element(&entry, 3)?.into();
+        let name = env.get_string(&name)?.into();
+        Ok(Some(name))
+    }
+
+    /// Get the weight of the font, from 100 (thin) to 900 (black). 400 is normal, 700 is bold.
+    pub fn get_font_weight(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        let names = self.get_font_names(env)?;
+        env.call_method(&names, "getFontWeight", "()I", &[])?.i()
+    }
+
+    /// Whether the license of the font allows embedding it in a document.
+    pub fn is_embeddable(&self, env: &mut JNIEnv<'a>) -> Result<bool> {
+        let names = self.get_font_names(env)?;
+        env.call_method(&names, "allowEmbedding", "()Z", &[])?.z()
+    }
+
+    /// Get the italic angle in degrees, counter-clockwise from the vertical. Upright fonts have an angle of 0.
+    pub fn get_italic_angle(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        let metrics = self.get_font_metrics(env)?;
+        env.call_method(&metrics, "getItalicAngle", "()F", &[])?.f()
+    }
+
+    pub fn get_units_per_em(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        let metrics = self.get_font_metrics(env)?;
+        env.call_method(&metrics, "getUnitsPerEm", "()I", &[])?.i()
+    }
+
+    /// Get the bounding box of all glyphs as `[x_min, y_min, x_max, y_max]`,
+    /// in glyph space units scaled to 1000 units per em.
+    pub fn get_bbox(&self, env: &mut JNIEnv<'a>) -> Result<[i32; 4]> {
+        let metrics = self.get_font_metrics(env)?;
+        let bbox: JIntArray = env
+            .call_method(&metrics, "getBbox", "()[I", &[])?
+            .l()?
+            .into();
+
+        let mut buf = [0; 4];
+        env.get_int_array_region(&bbox, 0, &mut buf)?;
+        Ok(buf)
+    }
+}
+
 impl<'a> FontProgramFactory<'a> {
     pub fn new_from_ttf(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<FontProgram<'a>> {
         let byte_array = env.new_byte_array(bytes.len() as jsize)?;
@@ -88,6 +615,65 @@ impl<'a> FontProgramFactory<'a> {
             .l()?;
         Ok(FontProgram(object))
     }
+
+    /// Create a font program from the bytes of a TrueType, OpenType (including CFF based) or WOFF font.
+    pub fn new_from_bytes(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<FontProgram<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let object = env
+            .call_static_method(
+                "com/itextpdf/io/font/FontProgramFactory",
+                "createFont",
+                "([B)Lcom/itextpdf/io/font/FontProgram;",
+                &[(&byte_array).into()],
+            )?
+            .l()?;
+        Ok(FontProgram(object))
+    }
+
+    /// Create a font program from a single face of a TrueType collection (`.ttc`).
+    /// Faces start at 0, see [FontProgramFactory::get_ttc_size].
+    pub fn new_from_ttc(
+        bytes: &[u8],
+        ttc_index: i32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<FontProgram<'a>> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let object = env
+            .call_static_method(
+                "com/itextpdf/io/font/FontProgramFactory",
+                "createFont",
+                "([BIZ)Lcom/itextpdf/io/font/FontProgram;",
+                &[(&byte_array).into(), ttc_index.into(), false.into()],
+            )?
+            .l()?;
+        Ok(FontProgram(object))
+    }
+
+    /// Get the number of faces in a TrueType collection.
+    pub fn get_ttc_size(bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<i32> {
+        let byte_array = new_byte_array(bytes, env)?;
+        let collection = env.new_object(
+            "com/itextpdf/io/font/TrueTypeCollection",
+            "([B)V",
+            &[(&byte_array).into()],
+        )?;
+        env.call_method(&collection, "getTTCSize", "()I", &[])?.i()
+    }
+
+    /// Create a Type 1 font program from its metrics (`.afm`) and glyphs (`.pfb`).
+    pub fn new_from_type1(afm: &[u8], pfb: &[u8], env: &mut JNIEnv<'a>) -> Result<FontProgram<'a>> {
+        let afm = new_byte_array(afm, env)?;
+        let pfb = new_byte_array(pfb, env)?;
+        let object = env
+            .call_static_method(
+                "com/itextpdf/io/font/FontProgramFactory",
+                "createType1Font",
+                "([B[B)Lcom/itextpdf/io/font/FontProgram;",
+                &[(&afm).into(), (&pfb).into()],
+            )?
+            .l()?;
+        Ok(FontProgram(object))
+    }
 }
 
 #[derive(Debug, Clone, Display)]
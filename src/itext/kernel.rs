@@ -1,16 +1,28 @@
 use crate::itext::io::{FontProgram, ImageData, PdfEncodings, StandardFont};
-use crate::java::ByteArrayOutputStream;
+use crate::itext::layout::{
+    Canvas, ElementPropertyContainer, Paragraph, TextAlignment, VerticalAlignment,
+};
+use crate::java::{ByteArrayInputStream, ByteArrayOutputStream};
 use crate::java_object;
 use convert_case::{Case, Casing};
 use jni::errors::Result;
-use jni::objects::{JObject, JValueGen};
-use jni::sys::jboolean;
-use jni::JNIEnv;
+use jni::objects::{JClass, JFloatArray, JObject};
+use jni::sys::{jboolean, jlong, jsize};
+use jni::{JNIEnv, NativeMethod};
+use std::ffi::c_void;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
 use strum_macros::Display;
 
 java_object!(PdfDocument);
 java_object!(PdfWriter);
+java_object!(PdfReader);
 java_object!(SolidLine);
+java_object!(DottedLine);
+java_object!(DashedLine);
+java_object!(CustomLine);
+java_object!(TransparentLine);
 java_object!(PageSize);
 java_object!(Color);
 java_object!(PdfFont);
@@ -20,6 +32,11 @@ java_object!(PdfPage);
 java_object!(PdfExtGState);
 java_object!(PdfFormXObject);
 java_object!(Rectangle);
+java_object!(PatternColor);
+java_object!(PdfShading);
+java_object!(PdfTilingPattern);
+java_object!(LinearGradientBuilder);
+java_object!(AccessibilityProperties);
 
 #[derive(Clone, Display)]
 pub enum ColorConstant {
@@ -48,6 +65,145 @@ impl<'a> PdfDocument<'a> {
         Ok(Self(obj))
     }
 
+    /// Open an existing document for stamping. Changes are written to `writer`.
+    pub fn new_stamping(
+        reader: &PdfReader<'a>,
+        writer: &PdfWriter<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/PdfDocument",
+            "(Lcom/itextpdf/kernel/pdf/PdfReader;Lcom/itextpdf/kernel/pdf/PdfWriter;)V",
+            &[reader.into(), writer.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Open an existing document for reading only.
+    pub fn new_from_reader(reader: &PdfReader<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/PdfDocument",
+            "(Lcom/itextpdf/kernel/pdf/PdfReader;)V",
+            &[reader.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn close(self, env: &mut JNIEnv<'a>) -> Result<()> {
+        env.call_method(&self, "close", "()V", &[])?;
+        Ok(())
+    }
+
+    /// Make the document a tagged PDF, adding a structure tree describing its logical structure.
+    /// Layout elements are tagged automatically, see [AccessibilityProperties].
+    pub fn set_tagged(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setTagged",
+            "()Lcom/itextpdf/kernel/pdf/PdfDocument;",
+            &[],
+        )?;
+        Ok(self)
+    }
+
+    pub fn is_tagged(&self, env: &mut JNIEnv<'a>) -> Result<bool> {
+        env.call_method(self, "isTagged", "()Z", &[])?.z()
+    }
+
+    /// Set the natural language of the document, e.g. `en-US`.
+    pub fn set_language(&self, language: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let catalog = self.get_catalog(env)?;
+        let language = env.new_string(language)?;
+        let language = env.new_object(
+            "com/itextpdf/kernel/pdf/PdfString",
+            "(Ljava/lang/String;)V",
+            &[(&language).into()],
+        )?;
+        env.call_method(
+            &catalog,
+            "setLang",
+            "(Lcom/itextpdf/kernel/pdf/PdfString;)V",
+            &[(&language).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_title(&self, title: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let info = env
+            .call_method(
+                self,
+                "getDocumentInfo",
+                "()Lcom/itextpdf/kernel/pdf/PdfDocumentInfo;",
+                &[],
+            )?
+            .l()?;
+        let title = env.new_string(title)?;
+        env.call_method(
+            &info,
+            "setTitle",
+            "(Ljava/lang/String;)Lcom/itextpdf/kernel/pdf/PdfDocumentInfo;",
+            &[(&title).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set whether viewers show the title of the document instead of its file name.
+    /// Other viewer preferences of the document are kept.
+    pub fn set_display_doc_title(&self, display: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let catalog = self.get_catalog(env)?;
+        let catalog_dictionary = env
+            .call_method(
+                &catalog,
+                "getPdfObject",
+                "()Lcom/itextpdf/kernel/pdf/PdfObject;",
+                &[],
+            )?
+            .l()?;
+        let key = pdf_name("ViewerPreferences", env)?;
+        let existing = env
+            .call_method(
+                &catalog_dictionary,
+                "getAsDictionary",
+                "(Lcom/itextpdf/kernel/pdf/PdfName;)Lcom/itextpdf/kernel/pdf/PdfDictionary;",
+                &[(&key).into()],
+            )?
+            .l()?;
+
+        // Keep the viewer preferences which are already set
+        let preferences = if existing.is_null() {
+            env.new_object("com/itextpdf/kernel/pdf/PdfViewerPreferences", "()V", &[])?
+        } else {
+            env.new_object(
+                "com/itextpdf/kernel/pdf/PdfViewerPreferences",
+                "(Lcom/itextpdf/kernel/pdf/PdfDictionary;)V",
+                &[(&existing).into()],
+            )?
+        };
+        env.call_method(
+            &preferences,
+            "setDisplayDocTitle",
+            "(Z)Lcom/itextpdf/kernel/pdf/PdfViewerPreferences;",
+            &[display.into()],
+        )?;
+        env.call_method(
+            &catalog,
+            "setViewerPreferences",
+            "(Lcom/itextpdf/kernel/pdf/PdfViewerPreferences;)Lcom/itextpdf/kernel/pdf/PdfCatalog;",
+            &[(&preferences).into()],
+        )?;
+        Ok(self)
+    }
+
+    fn get_catalog(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        env.call_method(
+            self,
+            "getCatalog",
+            "()Lcom/itextpdf/kernel/pdf/PdfCatalog;",
+            &[],
+        )?
+        .l()
+    }
+
     pub fn get_default_page_size(&self, env: &mut JNIEnv<'a>) -> Result<PageSize<'a>> {
         let obj = env
             .call_method(
@@ -76,6 +232,48 @@ impl<'a> PdfDocument<'a> {
         let obj = env.call_method(self, "getNumberOfPages", "()I", &[])?.i()?;
         Ok(obj)
     }
+
+    pub fn add_new_page(
+        &self,
+        page_size: &PageSize<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<PdfPage<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "addNewPage",
+                "(Lcom/itextpdf/kernel/geom/PageSize;)Lcom/itextpdf/kernel/pdf/PdfPage;",
+                &[page_size.into()],
+            )?
+            .l()?;
+        Ok(PdfPage(obj))
+    }
+
+    /// Append a page showing only the image, sized to the physical size of the image.
+    /// If the image has no resolution, 72 DPI is assumed.
+    ///
+    /// Combined with [ImageDataFactory::create_tiff_pages](crate::itext::io::ImageDataFactory::create_tiff_pages),
+    /// this converts a scanned multi-page TIFF to a PDF.
+    pub fn add_image_as_page(
+        &self,
+        image_data: &ImageData<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<PdfPage<'a>> {
+        let dpi = |dpi: i32| if dpi > 0 { dpi as f32 } else { 72.0 };
+        let width = image_data.get_width(env)? * 72.0 / dpi(image_data.get_dpi_x(env)?);
+        let height = image_data.get_height(env)? * 72.0 / dpi(image_data.get_dpi_y(env)?);
+
+        let page_size = PageSize::new(width, height, env)?;
+        let page = self.add_new_page(&page_size, env)?;
+
+        let canvas = PdfCanvas::new_with_page(&page, env)?;
+        canvas.add_image_with_transformation_matrix(
+            image_data, width, 0.0, 0.0, height, 0.0, 0.0, false, env,
+        )?;
+        canvas.release(env)?;
+
+        Ok(page)
+    }
 }
 
 impl<'a> PdfWriter<'a> {
@@ -89,6 +287,17 @@ impl<'a> PdfWriter<'a> {
     }
 }
 
+impl<'a> PdfReader<'a> {
+    pub fn new(byte_stream: &ByteArrayInputStream<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/PdfReader",
+            "(Ljava/io/InputStream;)V",
+            &[byte_stream.into()],
+        )?;
+        Ok(Self(obj))
+    }
+}
+
 impl<'a> SolidLine<'a> {
     pub fn new(line_width: f32, env: &mut JNIEnv<'a>) -> Result<Self> {
         let obj = env.new_object(
@@ -99,18 +308,236 @@ impl<'a> SolidLine<'a> {
         Ok(Self(obj))
     }
 
+    /// Set the color of the line to a color constant.
+    ///
+    /// This shadows [LineDrawer::set_color] for method calls on a [SolidLine];
+    /// use `LineDrawer::set_color(&line, &color, env)` to set any [Color].
+    #[deprecated(note = "use `LineDrawer::set_color` with `Color::from_constant`")]
     pub fn set_color(&self, color: ColorConstant, env: &mut JNIEnv<'a>) -> Result<()> {
-        let color_j = color.get_java_value(env)?;
+        let color = Color::from_constant(color, env)?;
+        LineDrawer::set_color(self, &color, env)?;
+        Ok(())
+    }
+}
+
+/// A Java `ILineDrawer`, drawing lines for separators and tab leaders.
+pub trait LineDrawer<'a>
+where
+    Self: AsRef<JObject<'a>>,
+{
+    fn set_color(&self, color: &Color<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
         env.call_method(
-            self,
+            self.as_ref(),
             "setColor",
             "(Lcom/itextpdf/kernel/colors/Color;)V",
-            &[(&color_j).into()],
+            &[color.into()],
         )?;
-        Ok(())
+        Ok(self)
+    }
+
+    fn get_color(&self, env: &mut JNIEnv<'a>) -> Result<Color<'a>> {
+        let obj = env
+            .call_method(
+                self.as_ref(),
+                "getColor",
+                "()Lcom/itextpdf/kernel/colors/Color;",
+                &[],
+            )?
+            .l()?;
+        Ok(Color(obj))
+    }
+
+    fn set_line_width(&self, line_width: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(self.as_ref(), "setLineWidth", "(F)V", &[line_width.into()])?;
+        Ok(self)
+    }
+
+    fn get_line_width(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self.as_ref(), "getLineWidth", "()F", &[])?
+            .f()
+    }
+
+    /// Wrap the line drawer, drawing it with the given opacity between `0.0` and `1.0`.
+    /// The color and line width of the returned drawer are those of this drawer.
+    fn with_opacity(&self, opacity: f32, env: &mut JNIEnv<'a>) -> Result<TransparentLine<'a>> {
+        let obj = env.new_object(
+            "nl/mrfriendly/itextrs/TransparentLineDrawer",
+            "(Lcom/itextpdf/kernel/pdf/canvas/draw/ILineDrawer;F)V",
+            &[self.as_ref().into(), opacity.into()],
+        )?;
+        Ok(TransparentLine(obj))
+    }
+}
+
+impl<'a, T: LineDrawer<'a>> LineDrawer<'a> for &T {}
+
+impl<'a> LineDrawer<'a> for SolidLine<'a> {}
+impl<'a> LineDrawer<'a> for DottedLine<'a> {}
+impl<'a> LineDrawer<'a> for DashedLine<'a> {}
+impl<'a> LineDrawer<'a> for CustomLine<'a> {}
+impl<'a> LineDrawer<'a> for TransparentLine<'a> {}
+
+impl<'a> DottedLine<'a> {
+    /// Create a dotted line with the given dot size and gap between the dots.
+    pub fn new(line_width: f32, gap: f32, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/canvas/draw/DottedLine",
+            "(FF)V",
+            &[line_width.into(), gap.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Set the gap between the dots.
+    pub fn set_gap(&self, gap: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(self, "setGap", "(F)V", &[gap.into()])?;
+        Ok(self)
+    }
+}
+
+impl<'a> DashedLine<'a> {
+    pub fn new(line_width: f32, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/canvas/draw/DashedLine",
+            "(F)V",
+            &[line_width.into()],
+        )?;
+        Ok(Self(obj))
+    }
+}
+
+impl<'a> AccessibilityProperties<'a> {
+    /// Set the structure role, e.g. `H1`, `Figure` or `Artifact`.
+    pub fn set_role(&self, role: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        self.set_string("setRole", role, env)
+    }
+
+    /// Set the alternate description, e.g. of a figure, read instead of the content.
+    pub fn set_alternate_description(
+        &self,
+        description: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        self.set_string("setAlternateDescription", description, env)
+    }
+
+    /// Set the text the content represents, e.g. for text drawn as an image.
+    pub fn set_actual_text(&self, actual_text: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        self.set_string("setActualText", actual_text, env)
+    }
+
+    /// Set the natural language of the content, e.g. `en-US`.
+    pub fn set_language(&self, language: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        self.set_string("setLanguage", language, env)
+    }
+
+    fn set_string(&self, method: &str, value: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let value = env.new_string(value)?;
+        env.call_method(
+            self,
+            method,
+            "(Ljava/lang/String;)Lcom/itextpdf/kernel/pdf/tagutils/AccessibilityProperties;",
+            &[(&value).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+const RUST_LINE_DRAWER_CLASS: &str = "nl/mrfriendly/itextrs/RustLineDrawer";
+
+static LINE_DRAWER_NATIVES_REGISTERED: OnceLock<()> = OnceLock::new();
+
+/// The callback drawing a [CustomLine] in the given area of the canvas.
+pub type DrawLineFn = dyn for<'l> Fn(&PdfCanvas<'l>, &Rectangle<'l>, &CustomLine<'l>, &mut JNIEnv<'l>) -> Result<()>
+    + Send
+    + Sync;
+
+impl<'a> CustomLine<'a> {
+    /// Create a line drawer drawing the line with the given closure. The closure is called with the canvas,
+    /// the area to draw in and the drawer itself, to access the configured color and line width.
+    ///
+    /// The closure may be called from any thread attached to the JVM, and is dropped once the drawer is garbage collected.
+    /// Without the `bundled` feature, the classes in `bundle/src/main/java` must be on the classpath.
+    pub fn new<F>(draw: F, env: &mut JNIEnv<'a>) -> Result<Self>
+    where
+        F: for<'l> Fn(
+                &PdfCanvas<'l>,
+                &Rectangle<'l>,
+                &CustomLine<'l>,
+                &mut JNIEnv<'l>,
+            ) -> Result<()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        if LINE_DRAWER_NATIVES_REGISTERED.get().is_none() {
+            env.register_native_methods(
+                RUST_LINE_DRAWER_CLASS,
+                &[
+                    NativeMethod {
+                        name: "draw".into(),
+                        sig: "(JLnl/mrfriendly/itextrs/RustLineDrawer;Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;Lcom/itextpdf/kernel/geom/Rectangle;)V".into(),
+                        fn_ptr: custom_line_draw as *mut c_void,
+                    },
+                    NativeMethod {
+                        name: "release".into(),
+                        sig: "(J)V".into(),
+                        fn_ptr: custom_line_release as *mut c_void,
+                    },
+                ],
+            )?;
+            let _ = LINE_DRAWER_NATIVES_REGISTERED.set(());
+        }
+
+        let draw: Box<Box<DrawLineFn>> = Box::new(Box::new(draw));
+        let handle = Box::into_raw(draw) as jlong;
+        match env.new_object(RUST_LINE_DRAWER_CLASS, "(J)V", &[handle.into()]) {
+            Ok(obj) => Ok(Self(obj)),
+            Err(e) => {
+                // The Java object was not created, so it will never release the closure
+                // SAFETY: the handle was created above and is not used elsewhere
+                drop(unsafe { Box::from_raw(handle as *mut Box<DrawLineFn>) });
+                Err(e)
+            }
+        }
+    }
+}
+
+extern "system" fn custom_line_draw<'l>(
+    mut env: JNIEnv<'l>,
+    _class: JClass<'l>,
+    handle: jlong,
+    drawer: JObject<'l>,
+    canvas: JObject<'l>,
+    draw_area: JObject<'l>,
+) {
+    // SAFETY: the handle is created in `CustomLine::new` and only released once the Java object is unreachable
+    let draw = unsafe { &*(handle as *const Box<DrawLineFn>) };
+    let drawer = CustomLine(drawer);
+    let canvas = PdfCanvas(canvas);
+    let draw_area = Rectangle(draw_area);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        draw(&canvas, &draw_area, &drawer, &mut env)
+    }));
+    match result {
+        Ok(Ok(())) => {}
+        // The exception is still pending, and is thrown once we return to Java
+        Ok(Err(jni::errors::Error::JavaException)) => {}
+        Ok(Err(e)) => {
+            let _ = env.throw_new("java/lang/RuntimeException", e.to_string());
+        }
+        Err(_) => {
+            let _ = env.throw_new("java/lang/RuntimeException", "Line drawer closure panicked");
+        }
     }
 }
 
+extern "system" fn custom_line_release(_env: JNIEnv, _class: JClass, handle: jlong) {
+    // SAFETY: the handle is created in `CustomLine::new` and released exactly once
+    drop(unsafe { Box::from_raw(handle as *mut Box<DrawLineFn>) });
+}
+
 impl<'a> Color<'a> {
     pub fn from_rgb(r: f32, g: f32, b: f32, env: &mut JNIEnv<'a>) -> Result<Self> {
         let obj = env.new_object(
@@ -126,6 +553,85 @@ impl<'a> Color<'a> {
     }
 }
 
+#[derive(Clone, Display)]
+pub enum GradientSpreadMethod {
+    Pad,
+    Reflect,
+    Repeat,
+    None,
+}
+
+#[derive(Clone, Display)]
+pub enum GradientOffsetType {
+    Absolute,
+    Auto,
+    Relative,
+}
+
+#[derive(Clone, Display)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+pub enum WatermarkContent<'a, 'b> {
+    Text {
+        text: &'b str,
+        font: &'b PdfFont<'a>,
+        font_size: f32,
+        color: &'b Color<'a>,
+    },
+    /// An image, drawn at its natural size multiplied by `scale`.
+    Image {
+        image_data: &'b ImageData<'a>,
+        scale: f32,
+    },
+}
+
+#[derive(Clone)]
+pub enum WatermarkLayer {
+    /// Draw the watermark over the existing page content.
+    OverContent,
+    /// Draw the watermark under the existing page content, e.g. for backgrounds.
+    UnderContent,
+}
+
+/// A watermark stamped in the center of pages of an existing document.
+pub struct Watermark<'a, 'b> {
+    pub content: WatermarkContent<'a, 'b>,
+    pub layer: WatermarkLayer,
+    /// Counter-clockwise rotation in degrees, as seen in a viewer.
+    pub rotation: f32,
+    /// Opacity in the range `0.0..=1.0`.
+    pub opacity: f32,
+}
+
+#[derive(Clone, Display)]
+pub enum EmbeddingStrategy {
+    /// Embed the font, failing if its license does not allow embedding.
+    ForceEmbedded,
+    /// Never embed the font, failing for fonts which must be embedded.
+    ForceNotEmbedded,
+    /// Embed the font if its license allows it.
+    PreferEmbedded,
+    /// Only embed the font if it must be embedded, e.g. for fonts with an Identity-H encoding.
+    PreferNotEmbedded,
+}
+
 impl ColorConstant {
     pub(crate) fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
         let field_name = self.to_string().to_case(Case::Constant);
@@ -194,7 +700,7 @@ page_size_constant!(
 impl<'a> PageSize<'a> {
     pub fn new(width: f32, height: f32, env: &mut JNIEnv<'a>) -> Result<Self> {
         Ok(Self(env.new_object(
-            "Lcom/itextpdf/kernel/geom/PageSize;",
+            "com/itextpdf/kernel/geom/PageSize",
             "(FF)V",
             &[width.into(), height.into()],
         )?))
@@ -209,6 +715,79 @@ impl<'a> PageSize<'a> {
     }
 }
 
+impl<'a> PdfFont<'a> {
+    /// Get the width of `text` in points, when set in this font at `font_size`.
+    pub fn get_width(&self, text: &str, font_size: f32, env: &mut JNIEnv<'a>) -> Result<f32> {
+        let text = env.new_string(text)?;
+        env.call_method(
+            self,
+            "getWidth",
+            "(Ljava/lang/String;F)F",
+            &[(&text).into(), font_size.into()],
+        )?
+        .f()
+    }
+
+    /// Get the maximum ascent of the glyphs in `text` in points, when set in this font at `font_size`.
+    /// The value is truncated to whole points by iText.
+    pub fn get_ascent(&self, text: &str, font_size: f32, env: &mut JNIEnv<'a>) -> Result<i32> {
+        let text = env.new_string(text)?;
+        env.call_method(
+            self,
+            "getAscent",
+            "(Ljava/lang/String;F)I",
+            &[(&text).into(), font_size.into()],
+        )?
+        .i()
+    }
+
+    /// Get the minimum descent of the glyphs in `text` in points, when set in this font at `font_size`.
+    /// The descent is negative for glyphs extending below the baseline, and truncated to whole points by iText.
+    pub fn get_descent(&self, text: &str, font_size: f32, env: &mut JNIEnv<'a>) -> Result<i32> {
+        let text = env.new_string(text)?;
+        env.call_method(
+            self,
+            "getDescent",
+            "(Ljava/lang/String;F)I",
+            &[(&text).into(), font_size.into()],
+        )?
+        .i()
+    }
+
+    /// Whether the font has a glyph for the character.
+    pub fn contains_glyph(&self, c: char, env: &mut JNIEnv<'a>) -> Result<bool> {
+        env.call_method(self, "containsGlyph", "(I)Z", &[(c as i32).into()])?
+            .z()
+    }
+
+    /// Whether only the glyphs used in the document are embedded. Enabled by default.
+    pub fn set_subset(&self, subset: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(self, "setSubset", "(Z)V", &[subset.into()])?;
+        Ok(self)
+    }
+
+    /// Always embed the glyphs for the characters in the inclusive range, in addition to the glyphs used
+    /// in the document. Only applies when subsetting is enabled.
+    pub fn add_subset_range(&self, first: char, last: char, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let range = env.new_int_array(2)?;
+        env.set_int_array_region(&range, 0, &[first as i32, last as i32])?;
+        env.call_method(self, "addSubsetRange", "([I)V", &[(&range).into()])?;
+        Ok(self)
+    }
+
+    pub fn get_font_program(&self, env: &mut JNIEnv<'a>) -> Result<FontProgram<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "getFontProgram",
+                "()Lcom/itextpdf/io/font/FontProgram;",
+                &[],
+            )?
+            .l()?;
+        Ok(FontProgram(obj))
+    }
+}
+
 impl<'a> PdfFontFactory<'a> {
     pub fn create_from_standard_font(
         standard_font: StandardFont,
@@ -257,18 +836,45 @@ impl<'a> PdfFontFactory<'a> {
         program: FontProgram,
         encoding: PdfEncodings,
         env: &mut JNIEnv<'a>,
+    ) -> Result<PdfFont<'a>> {
+        Self::create_from_program_with_embedding_strategy(
+            program,
+            encoding,
+            EmbeddingStrategy::ForceEmbedded,
+            env,
+        )
+    }
+
+    pub fn create_from_program_with_embedding_strategy(
+        program: FontProgram,
+        encoding: PdfEncodings,
+        embedding_strategy: EmbeddingStrategy,
+        env: &mut JNIEnv<'a>,
     ) -> Result<PdfFont<'a>> {
         let encoding = encoding.get_java_value(env)?;
+        let embedding_strategy = embedding_strategy.get_java_value(env)?;
         let object = env.call_static_method(
             "com/itextpdf/kernel/font/PdfFontFactory",
             "createFont",
-            "(Lcom/itextpdf/io/font/FontProgram;Ljava/lang/String;Z)Lcom/itextpdf/kernel/font/PdfFont;",
-            &[program.as_ref().into(), (&encoding).into(), JValueGen::Bool(true as jboolean)]
+            "(Lcom/itextpdf/io/font/FontProgram;Ljava/lang/String;Lcom/itextpdf/kernel/font/PdfFontFactory$EmbeddingStrategy;)Lcom/itextpdf/kernel/font/PdfFont;",
+            &[program.as_ref().into(), (&encoding).into(), (&embedding_strategy).into()]
         )?.l()?;
         Ok(PdfFont(object))
     }
 }
 
+impl EmbeddingStrategy {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = self.to_string().to_case(Case::Constant);
+        env.get_static_field(
+            "com/itextpdf/kernel/font/PdfFontFactory$EmbeddingStrategy",
+            field_name,
+            "Lcom/itextpdf/kernel/font/PdfFontFactory$EmbeddingStrategy;",
+        )?
+        .l()
+    }
+}
+
 impl<'a> PdfCanvas<'a> {
     pub fn new_with_page(page: &PdfPage<'a>, env: &mut JNIEnv<'a>) -> Result<PdfCanvas<'a>> {
         let obj = env.new_object(
@@ -279,6 +885,56 @@ impl<'a> PdfCanvas<'a> {
         Ok(Self(obj))
     }
 
+    /// Create a canvas drawing in a new content stream placed before the existing content of the page,
+    /// i.e. under the existing content.
+    pub fn new_under_content(page: &PdfPage<'a>, env: &mut JNIEnv<'a>) -> Result<PdfCanvas<'a>> {
+        let stream = env
+            .call_method(
+                page,
+                "newContentStreamBefore",
+                "()Lcom/itextpdf/kernel/pdf/PdfStream;",
+                &[],
+            )?
+            .l()?;
+        let resources = env
+            .call_method(
+                page,
+                "getResources",
+                "()Lcom/itextpdf/kernel/pdf/PdfResources;",
+                &[],
+            )?
+            .l()?;
+        let document = env
+            .call_method(
+                page,
+                "getDocument",
+                "()Lcom/itextpdf/kernel/pdf/PdfDocument;",
+                &[],
+            )?
+            .l()?;
+
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/canvas/PdfCanvas",
+            "(Lcom/itextpdf/kernel/pdf/PdfStream;Lcom/itextpdf/kernel/pdf/PdfResources;Lcom/itextpdf/kernel/pdf/PdfDocument;)V",
+            &[(&stream).into(), (&resources).into(), (&document).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Create a canvas drawing into the content of a form XObject.
+    pub fn new_with_form_x_object(
+        form_x_object: &PdfFormXObject<'a>,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<PdfCanvas<'a>> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/canvas/PdfCanvas",
+            "(Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;Lcom/itextpdf/kernel/pdf/PdfDocument;)V",
+            &[form_x_object.into(), pdf_document.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
     pub fn save_state(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
         env.call_method(
             self,
@@ -315,6 +971,7 @@ impl<'a> PdfCanvas<'a> {
         Ok(self)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_image_with_transformation_matrix(
         &self,
         image_data: &ImageData<'a>,
@@ -365,13 +1022,151 @@ impl<'a> PdfCanvas<'a> {
 
         Ok(self)
     }
-}
 
-impl<'a> PdfPage<'a> {
-    pub fn set_ignore_page_rotation_for_content(
-        &self,
-        ignore_page_rotation_for_content: bool,
-        env: &mut JNIEnv<'a>,
+    pub fn set_fill_color(&self, color: &Color<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setFillColor",
+            "(Lcom/itextpdf/kernel/colors/Color;)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[color.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_stroke_color(&self, color: &Color<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setStrokeColor",
+            "(Lcom/itextpdf/kernel/colors/Color;)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[color.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_line_width(&self, line_width: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setLineWidth",
+            "(F)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[line_width.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn rectangle(
+        &self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "rectangle",
+            "(DDDD)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[x.into(), y.into(), width.into(), height.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn move_to(&self, x: f64, y: f64, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "moveTo",
+            "(DD)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[x.into(), y.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn line_to(&self, x: f64, y: f64, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "lineTo",
+            "(DD)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[x.into(), y.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn fill(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "fill",
+            "()Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[],
+        )?;
+        Ok(self)
+    }
+
+    pub fn stroke(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "stroke",
+            "()Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[],
+        )?;
+        Ok(self)
+    }
+
+    pub fn fill_stroke(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "fillStroke",
+            "()Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[],
+        )?;
+        Ok(self)
+    }
+
+    /// Paint the shading over the current clipping area.
+    pub fn paint_shading(&self, shading: &PdfShading<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "paintShading",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/shading/AbstractPdfShading;)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[shading.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Mark the following content as an artifact, such as a page header or footer, which is not part of
+    /// the logical structure of a tagged document. End the artifact with [PdfCanvas::close_tag].
+    pub fn open_artifact(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let artifact =
+            env.new_object("com/itextpdf/kernel/pdf/canvas/CanvasArtifact", "()V", &[])?;
+        env.call_method(
+            self,
+            "openTag",
+            "(Lcom/itextpdf/kernel/pdf/canvas/CanvasTag;)Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[(&artifact).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn close_tag(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "closeTag",
+            "()Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;",
+            &[],
+        )?;
+        Ok(self)
+    }
+
+    /// Release the canvas. It should not be used afterwards.
+    pub fn release(self, env: &mut JNIEnv<'a>) -> Result<()> {
+        env.call_method(&self, "release", "()V", &[])?;
+        Ok(())
+    }
+}
+
+impl<'a> PdfPage<'a> {
+    pub fn set_ignore_page_rotation_for_content(
+        &self,
+        ignore_page_rotation_for_content: bool,
+        env: &mut JNIEnv<'a>,
     ) -> Result<&Self> {
         env.call_method(
             self,
@@ -382,6 +1177,11 @@ impl<'a> PdfPage<'a> {
         Ok(self)
     }
 
+    /// Get the page rotation in degrees, clockwise.
+    pub fn get_rotation(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "getRotation", "()I", &[])?.i()
+    }
+
     pub fn get_page_size(&self, env: &mut JNIEnv<'a>) -> Result<Rectangle<'a>> {
         let obj = env
             .call_method(
@@ -432,6 +1232,14 @@ impl<'a> Rectangle<'a> {
         Ok(Self(obj))
     }
 
+    pub fn get_width(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getWidth", "()F", &[])?.f()
+    }
+
+    pub fn get_height(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getHeight", "()F", &[])?.f()
+    }
+
     pub fn get_top(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
         let obj = env.call_method(self, "getTop", "()F", &[])?.f()?;
         Ok(obj)
@@ -472,6 +1280,920 @@ impl<'a> PdfExtGState<'a> {
         )?;
         Ok(self)
     }
+
+    pub fn set_stroke_opacity(
+        &self,
+        stroking_alpha_constant: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setStrokeOpacity",
+            "(F)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[stroking_alpha_constant.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_blend_mode(&self, blend_mode: BlendMode, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let blend_mode_j = blend_mode.get_java_value(env)?;
+        env.call_method(
+            self,
+            "setBlendMode",
+            "(Lcom/itextpdf/kernel/pdf/PdfName;)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[(&blend_mode_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_line_width(&self, line_width: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setLineWidth",
+            "(F)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[line_width.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_fill_overprint_flag(
+        &self,
+        fill_overprint: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setFillOverPrintFlag",
+            "(Z)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[fill_overprint.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_stroke_overprint_flag(
+        &self,
+        stroke_overprint: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setStrokeOverPrintFlag",
+            "(Z)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[stroke_overprint.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the overprint mode, either `0` or `1`.
+    pub fn set_overprint_mode(&self, overprint_mode: i32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setOverprintMode",
+            "(I)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[overprint_mode.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Whether the opacity and soft mask should be interpreted as shape values instead of opacity values.
+    pub fn set_alpha_is_shape(&self, alpha_is_shape: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setAlphaIsShape",
+            "(Z)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[alpha_is_shape.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_text_knockout(&self, text_knockout: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setTextKnockoutFlag",
+            "(Z)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[text_knockout.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Use the luminosity of `mask` as soft mask. White areas of the mask are fully opaque,
+    /// black areas are fully transparent.
+    pub fn set_soft_mask_luminosity(
+        &self,
+        mask: &PdfFormXObject<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        // A luminosity mask requires a transparency group with a color space
+        let group = env.new_object(
+            "com/itextpdf/kernel/pdf/xobject/PdfTransparencyGroup",
+            "()V",
+            &[],
+        )?;
+        let device_rgb = pdf_name("DeviceRGB", env)?;
+        env.call_method(
+            &group,
+            "setColorSpace",
+            "(Lcom/itextpdf/kernel/pdf/PdfName;)V",
+            &[(&device_rgb).into()],
+        )?;
+        env.call_method(
+            mask,
+            "setGroup",
+            "(Lcom/itextpdf/kernel/pdf/xobject/PdfTransparencyGroup;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+            &[(&group).into()],
+        )?;
+
+        let mask_stream = env
+            .call_method(
+                mask,
+                "getPdfObject",
+                "()Lcom/itextpdf/kernel/pdf/PdfObject;",
+                &[],
+            )?
+            .l()?;
+
+        let dict = env.new_object("com/itextpdf/kernel/pdf/PdfDictionary", "()V", &[])?;
+        for (key, value) in [("Type", "Mask"), ("S", "Luminosity")] {
+            let key = pdf_name(key, env)?;
+            let value = pdf_name(value, env)?;
+            env.call_method(
+                &dict,
+                "put",
+                "(Lcom/itextpdf/kernel/pdf/PdfName;Lcom/itextpdf/kernel/pdf/PdfObject;)Lcom/itextpdf/kernel/pdf/PdfObject;",
+                &[(&key).into(), (&value).into()],
+            )?;
+        }
+        let g = pdf_name("G", env)?;
+        env.call_method(
+            &dict,
+            "put",
+            "(Lcom/itextpdf/kernel/pdf/PdfName;Lcom/itextpdf/kernel/pdf/PdfObject;)Lcom/itextpdf/kernel/pdf/PdfObject;",
+            &[(&g).into(), (&mask_stream).into()],
+        )?;
+
+        env.call_method(
+            self,
+            "setSoftMask",
+            "(Lcom/itextpdf/kernel/pdf/PdfObject;)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[(&dict).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Remove any soft mask set in an enclosing graphics state.
+    pub fn set_no_soft_mask(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let none = pdf_name("None", env)?;
+        env.call_method(
+            self,
+            "setSoftMask",
+            "(Lcom/itextpdf/kernel/pdf/PdfObject;)Lcom/itextpdf/kernel/pdf/extgstate/PdfExtGState;",
+            &[(&none).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl BlendMode {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = format!("BM_{}", self.to_string().to_case(Case::Constant));
+        env.get_static_field(
+            "com/itextpdf/kernel/pdf/extgstate/PdfExtGState",
+            field_name,
+            "Lcom/itextpdf/kernel/pdf/PdfName;",
+        )?
+        .l()
+    }
+}
+
+/// Get one of the predefined `PdfName` constants, e.g. `DeviceRGB`.
+pub(crate) fn pdf_name<'a>(name: &str, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+    env.get_static_field(
+        "com/itextpdf/kernel/pdf/PdfName",
+        name,
+        "Lcom/itextpdf/kernel/pdf/PdfName;",
+    )?
+    .l()
 }
 
-impl<'a> PdfFormXObject<'a> {}
+impl<'a> PdfFormXObject<'a> {
+    pub fn new(bbox: &Rectangle<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/xobject/PdfFormXObject",
+            "(Lcom/itextpdf/kernel/geom/Rectangle;)V",
+            &[bbox.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Copy a page as a reusable template into `pdf_document`.
+    /// The page may belong to another document.
+    pub fn from_page(
+        page: &PdfPage<'a>,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let obj = env
+            .call_method(
+                page,
+                "copyAsFormXObject",
+                "(Lcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;",
+                &[pdf_document.into()],
+            )?
+            .l()?;
+        Ok(Self(obj))
+    }
+
+    pub fn get_width(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getWidth", "()F", &[])?.f()
+    }
+
+    pub fn get_height(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getHeight", "()F", &[])?.f()
+    }
+
+    pub fn get_bbox(&self, env: &mut JNIEnv<'a>) -> Result<Rectangle<'a>> {
+        let bbox = env
+            .call_method(self, "getBBox", "()Lcom/itextpdf/kernel/pdf/PdfArray;", &[])?
+            .l()?;
+        let obj = env
+            .call_method(
+                &bbox,
+                "toRectangle",
+                "()Lcom/itextpdf/kernel/geom/Rectangle;",
+                &[],
+            )?
+            .l()?;
+        Ok(Rectangle(obj))
+    }
+}
+
+/// The color space of a [PdfShading]. The colors of the stops have one component per color channel,
+/// in the range `0.0..=1.0`.
+#[derive(Clone, Copy, Debug)]
+pub enum ShadingColorSpace {
+    Gray,
+    Rgb,
+    Cmyk,
+}
+
+impl ShadingColorSpace {
+    pub fn components(&self) -> usize {
+        match self {
+            Self::Gray => 1,
+            Self::Rgb => 3,
+            Self::Cmyk => 4,
+        }
+    }
+
+    fn new_java_object<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let class = match self {
+            Self::Gray => "com/itextpdf/kernel/pdf/colorspace/PdfDeviceCs$Gray",
+            Self::Rgb => "com/itextpdf/kernel/pdf/colorspace/PdfDeviceCs$Rgb",
+            Self::Cmyk => "com/itextpdf/kernel/pdf/colorspace/PdfDeviceCs$Cmyk",
+        };
+        env.new_object(class, "()V", &[])
+    }
+}
+
+/// A color stop of a [PdfShading], at an offset in the range `0.0..=1.0` along the shading.
+#[derive(Clone, Debug)]
+pub struct ShadingStop {
+    pub offset: f32,
+    pub color: Vec<f32>,
+}
+
+impl ShadingStop {
+    pub fn new(offset: f32, color: &[f32]) -> Self {
+        Self {
+            offset,
+            color: color.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ShadingError {
+    /// A shading requires at least two stops.
+    TooFewStops(usize),
+    /// The color of a stop does not have the number of components of the color space.
+    InvalidColor {
+        color_space: ShadingColorSpace,
+        components: usize,
+    },
+    /// The offsets of the stops are not in increasing order, between 0 and 1.
+    InvalidOffsets,
+    Jni(jni::errors::Error),
+}
+
+impl fmt::Display for ShadingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewStops(stops) => {
+                write!(f, "A shading requires at least two stops, got {stops}")
+            }
+            Self::InvalidColor {
+                color_space,
+                components,
+            } => write!(
+                f,
+                "Expected {} color components for {color_space:?}, got {components}",
+                color_space.components()
+            ),
+            Self::InvalidOffsets => write!(
+                f,
+                "Shading stop offsets must be in increasing order, between 0 and 1"
+            ),
+            Self::Jni(e) => write!(f, "JNI error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ShadingError {}
+
+impl From<jni::errors::Error> for ShadingError {
+    fn from(value: jni::errors::Error) -> Self {
+        Self::Jni(value)
+    }
+}
+
+impl<'a> PdfShading<'a> {
+    /// Creates an axial (linear) shading between two points, blending from `start_color` to `end_color`.
+    /// Colors are RGB components in the range `0.0..=1.0`. `extend` controls whether the shading
+    /// continues beyond the start and end points respectively.
+    pub fn new_axial(
+        start: (f32, f32),
+        start_color: [f32; 3],
+        end: (f32, f32),
+        end_color: [f32; 3],
+        extend: [bool; 2],
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        Self::create_axial(
+            start,
+            end,
+            ShadingColorSpace::Rgb,
+            &[
+                ShadingStop::new(0.0, &start_color),
+                ShadingStop::new(1.0, &end_color),
+            ],
+            extend,
+            env,
+        )
+    }
+
+    /// Creates an axial (linear) shading between two points, blending between the color stops.
+    /// `extend` controls whether the shading continues beyond the start and end points respectively.
+    ///
+    /// At least two stops are required, with offsets in increasing order and colors matching the color space.
+    pub fn new_axial_with_stops(
+        start: (f32, f32),
+        end: (f32, f32),
+        color_space: ShadingColorSpace,
+        stops: &[ShadingStop],
+        extend: [bool; 2],
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<Self, ShadingError> {
+        validate_shading_stops(color_space, stops)?;
+        Ok(Self::create_axial(
+            start,
+            end,
+            color_space,
+            stops,
+            extend,
+            env,
+        )?)
+    }
+
+    fn create_axial(
+        start: (f32, f32),
+        end: (f32, f32),
+        color_space: ShadingColorSpace,
+        stops: &[ShadingStop],
+        extend: [bool; 2],
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let color_space_j = color_space.new_java_object(env)?;
+        let start_color_j = new_float_array(&stops[0].color, env)?;
+        let end_color_j = new_float_array(&stops[stops.len() - 1].color, env)?;
+        let extend_j = env.new_boolean_array(2)?;
+        env.set_boolean_array_region(&extend_j, 0, &extend.map(|x| x as jboolean))?;
+
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/colorspace/shading/PdfAxialShading",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/PdfColorSpace;FF[FFF[F[Z)V",
+            &[
+                (&color_space_j).into(),
+                start.0.into(),
+                start.1.into(),
+                (&start_color_j).into(),
+                end.0.into(),
+                end.1.into(),
+                (&end_color_j).into(),
+                (&extend_j).into(),
+            ],
+        )?;
+
+        let shading = Self(obj);
+        shading.set_stops(stops, env)?;
+        Ok(shading)
+    }
+
+    /// Creates a radial shading between two circles, given as `(x, y, radius)`.
+    /// Colors are RGB components in the range `0.0..=1.0`. `extend` controls whether the shading
+    /// continues beyond the start and end circles respectively.
+    pub fn new_radial(
+        start: (f32, f32, f32),
+        start_color: [f32; 3],
+        end: (f32, f32, f32),
+        end_color: [f32; 3],
+        extend: [bool; 2],
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        Self::create_radial(
+            start,
+            end,
+            ShadingColorSpace::Rgb,
+            &[
+                ShadingStop::new(0.0, &start_color),
+                ShadingStop::new(1.0, &end_color),
+            ],
+            extend,
+            env,
+        )
+    }
+
+    /// Creates a radial shading between two circles, given as `(x, y, radius)`, blending between the color stops.
+    /// `extend` controls whether the shading continues beyond the start and end circles respectively.
+    ///
+    /// At least two stops are required, with offsets in increasing order and colors matching the color space.
+    pub fn new_radial_with_stops(
+        start: (f32, f32, f32),
+        end: (f32, f32, f32),
+        color_space: ShadingColorSpace,
+        stops: &[ShadingStop],
+        extend: [bool; 2],
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<Self, ShadingError> {
+        validate_shading_stops(color_space, stops)?;
+        Ok(Self::create_radial(
+            start,
+            end,
+            color_space,
+            stops,
+            extend,
+            env,
+        )?)
+    }
+
+    fn create_radial(
+        start: (f32, f32, f32),
+        end: (f32, f32, f32),
+        color_space: ShadingColorSpace,
+        stops: &[ShadingStop],
+        extend: [bool; 2],
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let color_space_j = color_space.new_java_object(env)?;
+        let start_color_j = new_float_array(&stops[0].color, env)?;
+        let end_color_j = new_float_array(&stops[stops.len() - 1].color, env)?;
+        let extend_j = env.new_boolean_array(2)?;
+        env.set_boolean_array_region(&extend_j, 0, &extend.map(|x| x as jboolean))?;
+
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/colorspace/shading/PdfRadialShading",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/PdfColorSpace;FFF[FFFF[F[Z)V",
+            &[
+                (&color_space_j).into(),
+                start.0.into(),
+                start.1.into(),
+                start.2.into(),
+                (&start_color_j).into(),
+                end.0.into(),
+                end.1.into(),
+                end.2.into(),
+                (&end_color_j).into(),
+                (&extend_j).into(),
+            ],
+        )?;
+
+        let shading = Self(obj);
+        shading.set_stops(stops, env)?;
+        Ok(shading)
+    }
+
+    /// Replace the function of the shading by a stitching function (type 3) of
+    /// exponential interpolation functions (type 2), one between each pair of stops.
+    fn set_stops(&self, stops: &[ShadingStop], env: &mut JNIEnv<'a>) -> Result<()> {
+        // The shading is only defined on the domain of the function, so the outer colors are extended to 0 and 1
+        let mut stops = stops.to_vec();
+        if stops[0].offset > 0.0 {
+            stops.insert(0, ShadingStop::new(0.0, &stops[0].color.clone()));
+        }
+        if stops[stops.len() - 1].offset < 1.0 {
+            stops.push(ShadingStop::new(1.0, &stops[stops.len() - 1].color.clone()));
+        }
+
+        let functions = env.new_object("com/itextpdf/kernel/pdf/PdfArray", "()V", &[])?;
+        for pair in stops.windows(2) {
+            let function = env.new_object("com/itextpdf/kernel/pdf/PdfDictionary", "()V", &[])?;
+            let function_type = new_pdf_number(2.0, env)?;
+            let domain = new_pdf_array(&[0.0, 1.0], env)?;
+            let c0 = new_pdf_array(&pair[0].color, env)?;
+            let c1 = new_pdf_array(&pair[1].color, env)?;
+            let n = new_pdf_number(1.0, env)?;
+            for (key, value) in [
+                ("FunctionType", function_type),
+                ("Domain", domain),
+                ("C0", c0),
+                ("C1", c1),
+                ("N", n),
+            ] {
+                put_pdf_dictionary(&function, key, &value, env)?;
+            }
+
+            env.call_method(
+                &functions,
+                "add",
+                "(Lcom/itextpdf/kernel/pdf/PdfObject;)V",
+                &[(&function).into()],
+            )?;
+        }
+
+        let bounds: Vec<f32> = stops[1..stops.len() - 1]
+            .iter()
+            .map(|stop| stop.offset)
+            .collect();
+        let encode: Vec<f32> = stops.windows(2).flat_map(|_| [0.0, 1.0]).collect();
+
+        let function = env.new_object("com/itextpdf/kernel/pdf/PdfDictionary", "()V", &[])?;
+        let function_type = new_pdf_number(3.0, env)?;
+        let domain = new_pdf_array(&[0.0, 1.0], env)?;
+        let bounds = new_pdf_array(&bounds, env)?;
+        let encode = new_pdf_array(&encode, env)?;
+        for (key, value) in [
+            ("FunctionType", function_type),
+            ("Domain", domain),
+            ("Functions", functions),
+            ("Bounds", bounds),
+            ("Encode", encode),
+        ] {
+            put_pdf_dictionary(&function, key, &value, env)?;
+        }
+
+        let dict = env
+            .call_method(
+                self,
+                "getPdfObject",
+                "()Lcom/itextpdf/kernel/pdf/PdfObject;",
+                &[],
+            )?
+            .l()?;
+        put_pdf_dictionary(&dict, "Function", &function, env)
+    }
+}
+
+fn validate_shading_stops(
+    color_space: ShadingColorSpace,
+    stops: &[ShadingStop],
+) -> std::result::Result<(), ShadingError> {
+    if stops.len() < 2 {
+        return Err(ShadingError::TooFewStops(stops.len()));
+    }
+    if let Some(stop) = stops
+        .iter()
+        .find(|stop| stop.color.len() != color_space.components())
+    {
+        return Err(ShadingError::InvalidColor {
+            color_space,
+            components: stop.color.len(),
+        });
+    }
+    if stops.iter().any(|stop| !(0.0..=1.0).contains(&stop.offset))
+        || stops.windows(2).any(|pair| pair[0].offset > pair[1].offset)
+    {
+        return Err(ShadingError::InvalidOffsets);
+    }
+
+    Ok(())
+}
+
+fn new_float_array<'a>(values: &[f32], env: &mut JNIEnv<'a>) -> Result<JFloatArray<'a>> {
+    let array = env.new_float_array(values.len() as jsize)?;
+    env.set_float_array_region(&array, 0, values)?;
+    Ok(array)
+}
+
+fn new_pdf_array<'a>(values: &[f32], env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+    let array = new_float_array(values, env)?;
+    env.new_object(
+        "com/itextpdf/kernel/pdf/PdfArray",
+        "([F)V",
+        &[(&array).into()],
+    )
+}
+
+fn new_pdf_number<'a>(value: f64, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+    env.new_object("com/itextpdf/kernel/pdf/PdfNumber", "(D)V", &[value.into()])
+}
+
+fn put_pdf_dictionary(dict: &JObject, key: &str, value: &JObject, env: &mut JNIEnv) -> Result<()> {
+    let key = pdf_name(key, env)?;
+    env.call_method(
+        dict,
+        "put",
+        "(Lcom/itextpdf/kernel/pdf/PdfName;Lcom/itextpdf/kernel/pdf/PdfObject;)Lcom/itextpdf/kernel/pdf/PdfObject;",
+        &[(&key).into(), value.into()],
+    )?;
+    Ok(())
+}
+
+impl<'a> PdfTilingPattern<'a> {
+    /// Creates a tiling pattern with a cell of `width` by `height`.
+    /// An uncolored pattern only defines a shape, its color is provided when creating the [PatternColor].
+    pub fn new(width: f32, height: f32, colored: bool, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/colorspace/PdfPattern$Tiling",
+            "(FFZ)V",
+            &[width.into(), height.into(), colored.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn new_with_step(
+        bbox: &Rectangle<'a>,
+        x_step: f32,
+        y_step: f32,
+        colored: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/colorspace/PdfPattern$Tiling",
+            "(Lcom/itextpdf/kernel/geom/Rectangle;FFZ)V",
+            &[bbox.into(), x_step.into(), y_step.into(), colored.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Get a canvas drawing the content of a single pattern cell.
+    pub fn get_canvas(
+        &self,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<PdfCanvas<'a>> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/pdf/canvas/PdfPatternCanvas",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/PdfPattern$Tiling;Lcom/itextpdf/kernel/pdf/PdfDocument;)V",
+            &[self.into(), pdf_document.into()],
+        )?;
+        Ok(PdfCanvas(obj))
+    }
+}
+
+impl<'a> PatternColor<'a> {
+    pub fn new_shading(shading: &PdfShading<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let pattern = env.new_object(
+            "com/itextpdf/kernel/pdf/colorspace/PdfPattern$Shading",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/shading/AbstractPdfShading;)V",
+            &[shading.into()],
+        )?;
+        let obj = env.new_object(
+            "com/itextpdf/kernel/colors/PatternColor",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/PdfPattern;)V",
+            &[(&pattern).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn new_tiling(pattern: &PdfTilingPattern<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/colors/PatternColor",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/PdfPattern;)V",
+            &[pattern.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Create a color from an uncolored tiling pattern, painting its shape with `color`.
+    pub fn new_uncolored_tiling(
+        pattern: &PdfTilingPattern<'a>,
+        color: &Color<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/colors/PatternColor",
+            "(Lcom/itextpdf/kernel/pdf/colorspace/PdfPattern$Tiling;Lcom/itextpdf/kernel/colors/Color;)V",
+            &[pattern.into(), color.into()],
+        )?;
+        Ok(Self(obj))
+    }
+}
+
+impl<'a> From<PatternColor<'a>> for Color<'a> {
+    fn from(value: PatternColor<'a>) -> Self {
+        Color(value.0)
+    }
+}
+
+impl<'a> LinearGradientBuilder<'a> {
+    pub fn new(env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/kernel/colors/gradients/LinearGradientBuilder",
+            "()V",
+            &[],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Set the start and end points of the gradient vector.
+    /// When used as an element background, the coordinates are relative to the element's bounding box.
+    pub fn set_gradient_vector(
+        &self,
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setGradientVector",
+            "(DDDD)Lcom/itextpdf/kernel/colors/gradients/LinearGradientBuilder;",
+            &[x0.into(), y0.into(), x1.into(), y1.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Add a color stop. The RGB components are in the range `0.0..=1.0`.
+    pub fn add_color_stop(
+        &self,
+        rgb: [f32; 3],
+        offset: f64,
+        offset_type: GradientOffsetType,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let rgb_j = env.new_float_array(3)?;
+        env.set_float_array_region(&rgb_j, 0, &rgb)?;
+        let offset_type_j = offset_type.get_java_value(env)?;
+
+        let stop = env.new_object(
+            "com/itextpdf/kernel/colors/gradients/GradientColorStop",
+            "([FDLcom/itextpdf/kernel/colors/gradients/GradientColorStop$OffsetType;)V",
+            &[(&rgb_j).into(), offset.into(), (&offset_type_j).into()],
+        )?;
+
+        env.call_method(
+            self,
+            "addColorStop",
+            "(Lcom/itextpdf/kernel/colors/gradients/GradientColorStop;)Lcom/itextpdf/kernel/colors/gradients/AbstractLinearGradientBuilder;",
+            &[(&stop).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_spread_method(
+        &self,
+        spread_method: GradientSpreadMethod,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let spread_method_j = spread_method.get_java_value(env)?;
+        env.call_method(
+            self,
+            "setSpreadMethod",
+            "(Lcom/itextpdf/kernel/colors/gradients/GradientSpreadMethod;)Lcom/itextpdf/kernel/colors/gradients/AbstractLinearGradientBuilder;",
+            &[(&spread_method_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Build a color filling `target_bbox` with the gradient, for use with e.g. [PdfCanvas::set_fill_color].
+    pub fn build_color(
+        &self,
+        target_bbox: &Rectangle<'a>,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Color<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "buildColor",
+                "(Lcom/itextpdf/kernel/geom/Rectangle;Lcom/itextpdf/kernel/geom/AffineTransform;Lcom/itextpdf/kernel/pdf/PdfDocument;)Lcom/itextpdf/kernel/colors/Color;",
+                &[target_bbox.into(), (&JObject::null()).into(), pdf_document.into()],
+            )?
+            .l()?;
+        Ok(Color(obj))
+    }
+}
+
+impl GradientSpreadMethod {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = self.to_string().to_case(Case::Constant);
+        env.get_static_field(
+            "com/itextpdf/kernel/colors/gradients/GradientSpreadMethod",
+            field_name,
+            "Lcom/itextpdf/kernel/colors/gradients/GradientSpreadMethod;",
+        )?
+        .l()
+    }
+}
+
+impl GradientOffsetType {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = self.to_string().to_case(Case::Constant);
+        env.get_static_field(
+            "com/itextpdf/kernel/colors/gradients/GradientColorStop$OffsetType",
+            field_name,
+            "Lcom/itextpdf/kernel/colors/gradients/GradientColorStop$OffsetType;",
+        )?
+        .l()
+    }
+}
+
+impl<'a> Watermark<'a, '_> {
+    /// Stamp the watermark on the given pages, or on every page if `pages` is `None`.
+    /// Page numbers start at 1. The rotation of each page is taken into account,
+    /// so the watermark appears the same way up on every page.
+    pub fn apply(
+        &self,
+        pdf_document: &PdfDocument<'a>,
+        pages: Option<&[i32]>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<()> {
+        let pages = match pages {
+            Some(pages) => pages.to_vec(),
+            None => (1..=pdf_document.get_number_of_pages(env)?).collect(),
+        };
+
+        let ext_g_state = PdfExtGState::new(env)?;
+        ext_g_state.set_fill_opacity(env, self.opacity)?;
+        ext_g_state.set_stroke_opacity(self.opacity, env)?;
+
+        for page_num in pages {
+            let page = pdf_document.get_page(page_num, env)?;
+            let canvas = match self.layer {
+                WatermarkLayer::OverContent => PdfCanvas::new_with_page(&page, env)?,
+                WatermarkLayer::UnderContent => PdfCanvas::new_under_content(&page, env)?,
+            };
+
+            // Content is drawn in unrotated page space, which the viewer then rotates clockwise.
+            // The center of the page is the same in both spaces.
+            let page_size = page.get_page_size(env)?;
+            let center_x = page_size.get_left(env)? + page_size.get_width(env)? / 2.0;
+            let center_y = page_size.get_bottom(env)? + page_size.get_height(env)? / 2.0;
+            let angle = (self.rotation + page.get_rotation(env)? as f32).to_radians();
+
+            canvas.save_state(env)?;
+            canvas.set_ext_g_state(&ext_g_state, env)?;
+
+            match &self.content {
+                WatermarkContent::Text {
+                    text,
+                    font,
+                    font_size,
+                    color,
+                } => {
+                    // The root area is in the same unrotated page space as the center
+                    let layout_canvas = Canvas::new_from_canvas(&canvas, &page_size, env)?;
+                    let paragraph = Paragraph::new_with_text(text, env)?;
+                    paragraph
+                        .set_font(font, env)?
+                        .set_font_size(*font_size, env)?
+                        .set_font_color(color, env)?;
+
+                    layout_canvas.show_text_aligned(
+                        &paragraph,
+                        center_x,
+                        center_y,
+                        TextAlignment::Center,
+                        VerticalAlignment::Middle,
+                        angle,
+                        env,
+                    )?;
+                    layout_canvas.close(env)?;
+                }
+                WatermarkContent::Image { image_data, scale } => {
+                    let width = image_data.get_width(env)? * scale;
+                    let height = image_data.get_height(env)? * scale;
+                    let (sin, cos) = angle.sin_cos();
+
+                    // Maps the unit square of the image, rotated around its center, onto the page center
+                    canvas.add_image_with_transformation_matrix(
+                        image_data,
+                        width * cos,
+                        width * sin,
+                        -height * sin,
+                        height * cos,
+                        center_x - width / 2.0 * cos + height / 2.0 * sin,
+                        center_y - width / 2.0 * sin - height / 2.0 * cos,
+                        false,
+                        env,
+                    )?;
+                }
+            }
+
+            canvas.restore_state(env)?;
+            canvas.release(env)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,19 @@
-use crate::itext::io::ImageData;
+use crate::itext::io::{new_byte_array, FontProgram, ImageData};
 use crate::itext::kernel::{
-    Color, ColorConstant, PageSize, PdfCanvas, PdfDocument, PdfFont, PdfPage, Rectangle, SolidLine,
+    AccessibilityProperties, Color, ColorConstant, LineDrawer, LinearGradientBuilder, PageSize,
+    PdfCanvas, PdfDocument, PdfFont, PdfFormXObject, PdfPage, Rectangle, SolidLine,
 };
 use crate::java_object;
 use convert_case::{Case, Casing};
 use jni::errors::Result;
-use jni::objects::{JObject, JValueGen};
-use jni::sys::jsize;
-use jni::JNIEnv;
+use jni::objects::{JClass, JObject, JValueGen};
+use jni::sys::{jboolean, jint, jlong, jsize};
+use jni::{JNIEnv, NativeMethod};
+use std::ffi::c_void;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
 use strum_macros::Display;
 
 java_object!(Document);
@@ -15,8 +21,12 @@ java_object!(Table);
 java_object!(Cell);
 java_object!(Paragraph);
 java_object!(LineSeparator);
+java_object!(TabStop);
+java_object!(Tab);
 java_object!(Image);
 java_object!(Canvas);
+java_object!(FontProvider);
+java_object!(FontSet);
 
 pub trait RootElement<'a>
 where
@@ -41,6 +51,30 @@ where
         )?;
         Ok(self)
     }
+
+    /// Set the fonts from which a font is selected for each run of text,
+    /// based on the font family and the glyphs in the text.
+    /// See [ElementPropertyContainer::set_font_family].
+    fn set_font_provider(
+        &self,
+        font_provider: &FontProvider<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let property = env
+            .get_static_field(
+                "com/itextpdf/layout/properties/Property",
+                "FONT_PROVIDER",
+                "I",
+            )?
+            .i()?;
+        env.call_method(
+            self,
+            "setProperty",
+            "(ILjava/lang/Object;)V",
+            &[property.into(), font_provider.into()],
+        )?;
+        Ok(self)
+    }
 }
 
 impl<'a, T: RootElement<'a>> RootElement<'a> for &T {}
@@ -201,6 +235,166 @@ where
 
         Ok(self)
     }
+
+    /// Set the preferred font families, in order of preference. For each run of text,
+    /// the font provider of the root element selects a font containing its glyphs.
+    fn set_font_family(&self, families: &[&str], env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let array =
+            env.new_object_array(families.len() as jsize, "java/lang/String", JObject::null())?;
+        for (i, family) in families.iter().enumerate() {
+            let family = env.new_string(family)?;
+            env.set_object_array_element(&array, i as jsize, family)?;
+        }
+
+        env.call_method(
+            self.as_ref(),
+            "setFontFamily",
+            "([Ljava/lang/String;)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[(&array).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the base direction of the text.
+    /// Reordering right-to-left text requires the typography add-on, see [is_typography_available].
+    fn set_base_direction(
+        &self,
+        base_direction: BaseDirection,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let base_direction_j = base_direction.get_java_value(env)?;
+        env.call_method(
+            self.as_ref(),
+            "setBaseDirection",
+            "(Lcom/itextpdf/layout/properties/BaseDirection;)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[(&base_direction_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the script of the text, used to select the shaping rules of the font.
+    /// Shaping requires the typography add-on, see [is_typography_available].
+    fn set_script(&self, script: UnicodeScript, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let script_j = script.get_java_value(env)?;
+        let property = env
+            .get_static_field(
+                "com/itextpdf/layout/properties/Property",
+                "FONT_SCRIPT",
+                "I",
+            )?
+            .i()?;
+        env.call_method(
+            self.as_ref(),
+            "setProperty",
+            "(ILjava/lang/Object;)V",
+            &[property.into(), (&script_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Enable hyphenation of the text, using the hyphenation patterns bundled with iText.
+    fn set_hyphenation(&self, config: &HyphenationConfig, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let lang = env.new_string(&config.lang)?;
+        let country = match &config.country {
+            Some(country) => JObject::from(env.new_string(country)?),
+            None => JObject::null(),
+        };
+        let config_j = env.new_object(
+            "com/itextpdf/layout/hyphenation/HyphenationConfig",
+            "(Ljava/lang/String;Ljava/lang/String;II)V",
+            &[
+                (&lang).into(),
+                (&country).into(),
+                config.left_min.into(),
+                config.right_min.into(),
+            ],
+        )?;
+
+        env.call_method(
+            self.as_ref(),
+            "setHyphenation",
+            "(Lcom/itextpdf/layout/hyphenation/HyphenationConfig;)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[(&config_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set the strategy deciding where lines may be broken.
+    fn set_split_characters(
+        &self,
+        split_characters: SplitCharacters,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let split_characters_j = split_characters.new_java_object(env)?;
+        env.call_method(
+            self.as_ref(),
+            "setSplitCharacters",
+            "(Lcom/itextpdf/layout/splitting/ISplitCharacters;)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[(&split_characters_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    fn set_background_color(&self, color: &Color<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setBackgroundColor",
+            "(Lcom/itextpdf/kernel/colors/Color;)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[color.into()],
+        )?;
+        Ok(self)
+    }
+
+    fn set_background_color_with_opacity(
+        &self,
+        color: &Color<'a>,
+        opacity: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self.as_ref(),
+            "setBackgroundColor",
+            "(Lcom/itextpdf/kernel/colors/Color;F)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[color.into(), JValueGen::Float(opacity)],
+        )?;
+        Ok(self)
+    }
+
+    /// Fill the background of the element with a linear gradient.
+    /// The gradient vector is relative to the element's background area.
+    fn set_background_gradient(
+        &self,
+        gradient: &LinearGradientBuilder<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let builder = env.new_object(
+            "com/itextpdf/layout/properties/BackgroundImage$Builder",
+            "()V",
+            &[],
+        )?;
+        env.call_method(
+            &builder,
+            "setLinearGradientBuilder",
+            "(Lcom/itextpdf/kernel/colors/gradients/AbstractLinearGradientBuilder;)Lcom/itextpdf/layout/properties/BackgroundImage$Builder;",
+            &[gradient.into()],
+        )?;
+        let background_image = env
+            .call_method(
+                &builder,
+                "build",
+                "()Lcom/itextpdf/layout/properties/BackgroundImage;",
+                &[],
+            )?
+            .l()?;
+
+        env.call_method(
+            self.as_ref(),
+            "setBackgroundImage",
+            "(Lcom/itextpdf/layout/properties/BackgroundImage;)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[(&background_image).into()],
+        )?;
+        Ok(self)
+    }
 }
 
 impl<'a, T: ElementPropertyContainer<'a>> ElementPropertyContainer<'a> for &T {}
@@ -286,6 +480,28 @@ pub trait Element<'a>
 where
     Self: AsRef<JObject<'a>>,
 {
+    /// Get the properties used to tag the element in a tagged document.
+    fn get_accessibility_properties(
+        &self,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<AccessibilityProperties<'a>> {
+        let obj = env
+            .call_method(
+                self.as_ref(),
+                "getAccessibilityProperties",
+                "()Lcom/itextpdf/kernel/pdf/tagutils/AccessibilityProperties;",
+                &[],
+            )?
+            .l()?;
+        Ok(AccessibilityProperties(obj))
+    }
+
+    /// Mark the element as an artifact, such as a page header or footer, excluding it from the logical structure.
+    fn mark_as_artifact(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        self.get_accessibility_properties(env)?
+            .set_role("Artifact", env)?;
+        Ok(self)
+    }
 }
 
 impl<'a, T: Element<'a>> Element<'a> for &T {}
@@ -310,6 +526,145 @@ pub enum Border {
     NoBorder,
 }
 
+#[derive(Clone, Display)]
+pub enum BaseDirection {
+    NoBidi,
+    DefaultBidi,
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A Unicode script, see `java.lang.Character.UnicodeScript`.
+#[derive(Clone)]
+pub enum UnicodeScript {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Syriac,
+    Thaana,
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Sinhala,
+    Thai,
+    Lao,
+    Tibetan,
+    Myanmar,
+    Georgian,
+    Hangul,
+    Ethiopic,
+    Khmer,
+    Mongolian,
+    Hiragana,
+    Katakana,
+    Han,
+    /// Any other script, by the name of its `java.lang.Character.UnicodeScript` constant.
+    Other(String),
+}
+
+/// Hyphenation settings, see [ElementPropertyContainer::set_hyphenation].
+#[derive(Clone, Debug)]
+pub struct HyphenationConfig {
+    /// ISO 639 language code, e.g. `"en"`.
+    pub lang: String,
+    /// ISO 3166 country code, e.g. `"GB"`, to select a regional variant of the patterns.
+    pub country: Option<String>,
+    /// Minimum number of characters before the hyphen.
+    pub left_min: i32,
+    /// Minimum number of characters after the hyphen.
+    pub right_min: i32,
+}
+
+impl HyphenationConfig {
+    pub fn new(lang: &str, left_min: i32, right_min: i32) -> Self {
+        Self {
+            lang: lang.to_string(),
+            country: None,
+            left_min,
+            right_min,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum SplitCharacters {
+    /// Break lines after spaces and hyphens, and between CJK characters.
+    Default,
+    /// Never break inside a word, including CJK text.
+    KeepAll,
+    /// Break lines between any two characters, useful for long URLs.
+    BreakAll,
+    /// Decide with a closure, see [SplitCharacters::custom].
+    Custom(Arc<SplitCharactersFn>),
+}
+
+/// The callback of [SplitCharacters::Custom], deciding whether a line may be broken after the current character.
+pub type SplitCharactersFn = dyn Fn(&SplitCandidate) -> bool + Send + Sync;
+
+/// A character after which a line may be broken, with its neighbours.
+/// Characters are `None` at the start or end of the text, or for glyphs without a Unicode mapping.
+#[derive(Clone, Debug)]
+pub struct SplitCandidate {
+    pub previous: Option<char>,
+    pub current: Option<char>,
+    pub next: Option<char>,
+}
+
+#[derive(Clone)]
+pub enum TabAlignment {
+    Left,
+    Right,
+    Center,
+    /// Align the text on the first occurrence of the anchor character, e.g. the decimal separator.
+    /// The character must be in the Basic Multilingual Plane.
+    Anchor(char),
+}
+
+#[derive(Debug)]
+pub enum TabStopError {
+    /// The anchor character is outside the Basic Multilingual Plane, so it is not a single Java `char`.
+    AnchorOutsideBmp(char),
+    Jni(jni::errors::Error),
+}
+
+impl fmt::Display for TabStopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnchorOutsideBmp(anchor) => write!(
+                f,
+                "Tab anchor '{anchor}' is outside the Basic Multilingual Plane"
+            ),
+            Self::Jni(e) => write!(f, "JNI error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for TabStopError {}
+
+impl From<jni::errors::Error> for TabStopError {
+    fn from(value: jni::errors::Error) -> Self {
+        Self::Jni(value)
+    }
+}
+
+#[derive(Clone, Display)]
+pub enum ObjectFit {
+    Fill,
+    Contain,
+    Cover,
+    ScaleDown,
+    None,
+}
+
 #[derive(Clone)]
 pub enum TextAlignment {
     Left,
@@ -472,7 +827,7 @@ impl HorizontalAlignment {
 }
 
 impl VerticalAlignment {
-    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+    pub(crate) fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
         let field_name = self.to_string().to_case(Case::Constant);
 
         let obj = env
@@ -486,6 +841,214 @@ impl VerticalAlignment {
     }
 }
 
+impl BaseDirection {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = self.to_string().to_case(Case::Constant);
+
+        let obj = env
+            .get_static_field(
+                "com/itextpdf/layout/properties/BaseDirection",
+                field_name,
+                "Lcom/itextpdf/layout/properties/BaseDirection;",
+            )?
+            .l()?;
+        Ok(obj)
+    }
+}
+
+impl UnicodeScript {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = match self {
+            Self::Latin => "LATIN",
+            Self::Greek => "GREEK",
+            Self::Cyrillic => "CYRILLIC",
+            Self::Armenian => "ARMENIAN",
+            Self::Hebrew => "HEBREW",
+            Self::Arabic => "ARABIC",
+            Self::Syriac => "SYRIAC",
+            Self::Thaana => "THAANA",
+            Self::Devanagari => "DEVANAGARI",
+            Self::Bengali => "BENGALI",
+            Self::Gurmukhi => "GURMUKHI",
+            Self::Gujarati => "GUJARATI",
+            Self::Oriya => "ORIYA",
+            Self::Tamil => "TAMIL",
+            Self::Telugu => "TELUGU",
+            Self::Kannada => "KANNADA",
+            Self::Malayalam => "MALAYALAM",
+            Self::Sinhala => "SINHALA",
+            Self::Thai => "THAI",
+            Self::Lao => "LAO",
+            Self::Tibetan => "TIBETAN",
+            Self::Myanmar => "MYANMAR",
+            Self::Georgian => "GEORGIAN",
+            Self::Hangul => "HANGUL",
+            Self::Ethiopic => "ETHIOPIC",
+            Self::Khmer => "KHMER",
+            Self::Mongolian => "MONGOLIAN",
+            Self::Hiragana => "HIRAGANA",
+            Self::Katakana => "KATAKANA",
+            Self::Han => "HAN",
+            Self::Other(name) => name,
+        };
+
+        let obj = env
+            .get_static_field(
+                "java/lang/Character$UnicodeScript",
+                field_name,
+                "Ljava/lang/Character$UnicodeScript;",
+            )?
+            .l()?;
+        Ok(obj)
+    }
+}
+
+/// Whether iText's typography add-on is on the classpath. Without it, complex scripts such as Arabic
+/// are not shaped and right-to-left text is not reordered.
+pub fn is_typography_available(env: &mut JNIEnv) -> Result<bool> {
+    env.call_static_method(
+        "com/itextpdf/layout/renderer/TypographyUtils",
+        "isPdfCalligraphAvailable",
+        "()Z",
+        &[],
+    )?
+    .z()
+}
+
+impl TabAlignment {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = match self {
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+            Self::Center => "CENTER",
+            Self::Anchor(_) => "ANCHOR",
+        };
+
+        let obj = env
+            .get_static_field(
+                "com/itextpdf/layout/properties/TabAlignment",
+                field_name,
+                "Lcom/itextpdf/layout/properties/TabAlignment;",
+            )?
+            .l()?;
+        Ok(obj)
+    }
+}
+
+const RUST_SPLIT_CHARACTERS_CLASS: &str = "nl/mrfriendly/itextrs/RustSplitCharacters";
+
+static SPLIT_CHARACTERS_NATIVES_REGISTERED: OnceLock<()> = OnceLock::new();
+
+impl SplitCharacters {
+    /// Decide with a closure whether a line may be broken after a character.
+    ///
+    /// The closure may be called from any thread attached to the JVM. If it panics, a `RuntimeException` is thrown.
+    /// Without the `bundled` feature, the classes in `bundle/src/main/java` must be on the classpath.
+    pub fn custom<F>(is_split_character: F) -> Self
+    where
+        F: Fn(&SplitCandidate) -> bool + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(is_split_character))
+    }
+
+    fn new_java_object<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let class = match self {
+            Self::Default => "com/itextpdf/layout/splitting/DefaultSplitCharacters",
+            Self::KeepAll => "com/itextpdf/layout/splitting/KeepAllSplitCharacters",
+            Self::BreakAll => "com/itextpdf/layout/splitting/BreakAllSplitCharacters",
+            Self::Custom(is_split_character) => {
+                return new_rust_split_characters(is_split_character.clone(), env)
+            }
+        };
+
+        env.new_object(class, "()V", &[])
+    }
+}
+
+fn new_rust_split_characters<'a>(
+    is_split_character: Arc<SplitCharactersFn>,
+    env: &mut JNIEnv<'a>,
+) -> Result<JObject<'a>> {
+    if SPLIT_CHARACTERS_NATIVES_REGISTERED.get().is_none() {
+        env.register_native_methods(
+            RUST_SPLIT_CHARACTERS_CLASS,
+            &[
+                NativeMethod {
+                    name: "isSplitCharacter".into(),
+                    sig: "(JIII)Z".into(),
+                    fn_ptr: split_characters_is_split_character as *mut c_void,
+                },
+                NativeMethod {
+                    name: "release".into(),
+                    sig: "(J)V".into(),
+                    fn_ptr: split_characters_release as *mut c_void,
+                },
+            ],
+        )?;
+        let _ = SPLIT_CHARACTERS_NATIVES_REGISTERED.set(());
+    }
+
+    let handle = Box::into_raw(Box::new(is_split_character)) as jlong;
+    match env.new_object(RUST_SPLIT_CHARACTERS_CLASS, "(J)V", &[handle.into()]) {
+        Ok(obj) => Ok(obj),
+        Err(e) => {
+            // The Java object was not created, so it will never release the closure
+            // SAFETY: the handle was created above and is not used elsewhere
+            drop(unsafe { Box::from_raw(handle as *mut Arc<SplitCharactersFn>) });
+            Err(e)
+        }
+    }
+}
+
+extern "system" fn split_characters_is_split_character(
+    mut env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+    previous: jint,
+    current: jint,
+    next: jint,
+) -> jboolean {
+    // SAFETY: the handle is created in `new_rust_split_characters` and only released once the Java object is unreachable
+    let is_split_character = unsafe { &*(handle as *const Arc<SplitCharactersFn>) };
+    let to_char = |code_point: jint| u32::try_from(code_point).ok().and_then(char::from_u32);
+    let candidate = SplitCandidate {
+        previous: to_char(previous),
+        current: to_char(current),
+        next: to_char(next),
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| is_split_character(&candidate))) {
+        Ok(is_split) => is_split as jboolean,
+        Err(_) => {
+            let _ = env.throw_new(
+                "java/lang/RuntimeException",
+                "Split characters closure panicked",
+            );
+            false as jboolean
+        }
+    }
+}
+
+extern "system" fn split_characters_release(_env: JNIEnv, _class: JClass, handle: jlong) {
+    // SAFETY: the handle is created in `new_rust_split_characters` and released exactly once
+    drop(unsafe { Box::from_raw(handle as *mut Arc<SplitCharactersFn>) });
+}
+
+impl ObjectFit {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        let field_name = self.to_string().to_case(Case::Constant);
+
+        let obj = env
+            .get_static_field(
+                "com/itextpdf/layout/properties/ObjectFit",
+                field_name,
+                "Lcom/itextpdf/layout/properties/ObjectFit;",
+            )?
+            .l()?;
+        Ok(obj)
+    }
+}
+
 impl Border {
     fn get_java_constant<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
         Ok(match self {
@@ -503,7 +1066,7 @@ impl Border {
 }
 
 impl TextAlignment {
-    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+    pub(crate) fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
         let field_name = match self {
             Self::Left => "LEFT",
             Self::Center => "CENTER",
@@ -588,34 +1151,171 @@ impl<'a> Paragraph<'a> {
 
         Ok(Self(obj))
     }
-}
 
-impl<'a> BlockElement<'a> for LineSeparator<'a> {}
-impl<'a> ElementPropertyContainer<'a> for LineSeparator<'a> {}
-impl<'a> Element<'a> for LineSeparator<'a> {}
+    pub fn add_text(&self, text: &str, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let string = env.new_string(text)?;
+        env.call_method(
+            self,
+            "add",
+            "(Ljava/lang/String;)Lcom/itextpdf/layout/element/Paragraph;",
+            &[(&string).into()],
+        )?;
+        Ok(self)
+    }
 
-impl<'a> LineSeparator<'a> {
-    pub fn new_solid(line: SolidLine<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
-        let obj = env.new_object(
-            "com/itextpdf/layout/element/LineSeparator",
-            "(Lcom/itextpdf/kernel/pdf/canvas/draw/ILineDrawer;)V",
-            &[(&line).into()],
+    /// Add a tab, moving the following text to the next tab stop.
+    pub fn add_tab(&self, tab: &Tab<'a>, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "add",
+            "(Lcom/itextpdf/layout/element/ILeafElement;)Lcom/itextpdf/layout/element/Paragraph;",
+            &[tab.into()],
         )?;
-        Ok(Self(obj))
+        Ok(self)
     }
-}
 
-impl<'a> ElementPropertyContainer<'a> for Image<'a> {}
-impl<'a> Element<'a> for Image<'a> {}
+    pub fn add_tab_stops(&self, tab_stops: &[TabStop<'a>], env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let array = env.new_object_array(
+            tab_stops.len() as jsize,
+            "com/itextpdf/layout/element/TabStop",
+            JObject::null(),
+        )?;
+        for (i, tab_stop) in tab_stops.iter().enumerate() {
+            env.set_object_array_element(&array, i as jsize, tab_stop)?;
+        }
 
-impl<'a> Image<'a> {
-    pub fn new(image_data: ImageData<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
-        let obj = env.new_object(
-            "com/itextpdf/layout/element/Image",
-            "(Lcom/itextpdf/io/image/ImageData;)V",
-            &[(&image_data).into()],
+        env.call_method(
+            self,
+            "addTabStops",
+            "([Lcom/itextpdf/layout/element/TabStop;)Lcom/itextpdf/layout/element/Paragraph;",
+            &[(&array).into()],
         )?;
-        Ok(Self(obj))
+        Ok(self)
+    }
+
+    /// Set the distance between the default tab stops, used beyond the last explicit tab stop.
+    pub fn set_default_tab_stop(&self, distance: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let distance_j = env.new_object("java/lang/Float", "(F)V", &[distance.into()])?;
+        let property = env
+            .get_static_field(
+                "com/itextpdf/layout/properties/Property",
+                "TAB_DEFAULT",
+                "I",
+            )?
+            .i()?;
+        env.call_method(
+            self,
+            "setProperty",
+            "(ILjava/lang/Object;)V",
+            &[property.into(), (&distance_j).into()],
+        )?;
+        Ok(self)
+    }
+}
+
+impl<'a> TabStop<'a> {
+    /// Create a tab stop at the given position.
+    pub fn new(
+        position: f32,
+        alignment: TabAlignment,
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<Self, TabStopError> {
+        Self::create(position, alignment, &JObject::null(), env)
+    }
+
+    /// Create a tab stop at the given position, filling the space before it with a leader.
+    pub fn with_leader<L: LineDrawer<'a>>(
+        position: f32,
+        alignment: TabAlignment,
+        leader: L,
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<Self, TabStopError> {
+        Self::create(position, alignment, leader.as_ref(), env)
+    }
+
+    fn create(
+        position: f32,
+        alignment: TabAlignment,
+        leader: &JObject<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<Self, TabStopError> {
+        // A Java char is a single UTF-16 code unit
+        let anchor = match alignment {
+            TabAlignment::Anchor(anchor) => Some(
+                u16::try_from(u32::from(anchor))
+                    .map_err(|_| TabStopError::AnchorOutsideBmp(anchor))?,
+            ),
+            _ => None,
+        };
+
+        let alignment_j = alignment.get_java_value(env)?;
+        let obj = env.new_object(
+            "com/itextpdf/layout/element/TabStop",
+            "(FLcom/itextpdf/layout/properties/TabAlignment;Lcom/itextpdf/kernel/pdf/canvas/draw/ILineDrawer;)V",
+            &[position.into(), (&alignment_j).into(), leader.into()],
+        )?;
+
+        if let Some(anchor) = anchor {
+            let anchor_j = env.new_object("java/lang/Character", "(C)V", &[anchor.into()])?;
+            env.call_method(
+                &obj,
+                "setTabAnchor",
+                "(Ljava/lang/Character;)V",
+                &[(&anchor_j).into()],
+            )?;
+        }
+
+        Ok(Self(obj))
+    }
+}
+
+impl<'a> Tab<'a> {
+    pub fn new(env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object("com/itextpdf/layout/element/Tab", "()V", &[])?;
+        Ok(Self(obj))
+    }
+}
+
+impl<'a> BlockElement<'a> for LineSeparator<'a> {}
+impl<'a> ElementPropertyContainer<'a> for LineSeparator<'a> {}
+impl<'a> Element<'a> for LineSeparator<'a> {}
+
+impl<'a> LineSeparator<'a> {
+    pub fn new<L: LineDrawer<'a>>(line: L, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/layout/element/LineSeparator",
+            "(Lcom/itextpdf/kernel/pdf/canvas/draw/ILineDrawer;)V",
+            &[line.as_ref().into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn new_solid(line: SolidLine<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        Self::new(line, env)
+    }
+}
+
+impl<'a> ElementPropertyContainer<'a> for Image<'a> {}
+impl<'a> Element<'a> for Image<'a> {}
+
+impl<'a> Image<'a> {
+    pub fn new(image_data: ImageData<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/layout/element/Image",
+            "(Lcom/itextpdf/io/image/ImageData;)V",
+            &[(&image_data).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Create an image showing a form XObject, e.g. a template or a barcode.
+    pub fn new_from_x_object(x_object: &PdfFormXObject<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/layout/element/Image",
+            "(Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;)V",
+            &[x_object.into()],
+        )?;
+        Ok(Self(obj))
     }
 
     pub fn set_width(&self, width: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
@@ -637,6 +1337,228 @@ impl<'a> Image<'a> {
         )?;
         Ok(self)
     }
+
+    /// Scale the image to fit within the given size, keeping its aspect ratio.
+    pub fn scale_to_fit(
+        &self,
+        fit_width: f32,
+        fit_height: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "scaleToFit",
+            "(FF)Lcom/itextpdf/layout/element/Image;",
+            &[fit_width.into(), fit_height.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Scale the image to exactly the given size, ignoring its aspect ratio.
+    pub fn scale_absolute(
+        &self,
+        fit_width: f32,
+        fit_height: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "scaleAbsolute",
+            "(FF)Lcom/itextpdf/layout/element/Image;",
+            &[fit_width.into(), fit_height.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Scale the image to fit the available area, keeping its aspect ratio.
+    pub fn set_auto_scale(&self, auto_scale: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setAutoScale",
+            "(Z)Lcom/itextpdf/layout/element/Image;",
+            &[auto_scale.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Scale the width of the image to fit the available width.
+    pub fn set_auto_scale_width(&self, auto_scale: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setAutoScaleWidth",
+            "(Z)Lcom/itextpdf/layout/element/Image;",
+            &[auto_scale.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Scale the height of the image to fit the available height.
+    pub fn set_auto_scale_height(&self, auto_scale: bool, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setAutoScaleHeight",
+            "(Z)Lcom/itextpdf/layout/element/Image;",
+            &[auto_scale.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Rotate the image counter-clockwise by `rad_angle` radians.
+    pub fn set_rotation_angle(&self, rad_angle: f64, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setRotationAngle",
+            "(D)Lcom/itextpdf/layout/element/Image;",
+            &[rad_angle.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Place the image at an absolute position on the given page. Pages start at 1.
+    pub fn set_fixed_position_on_page(
+        &self,
+        page_number: i32,
+        left: f32,
+        bottom: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setFixedPosition",
+            "(IFF)Lcom/itextpdf/layout/element/Image;",
+            &[page_number.into(), left.into(), bottom.into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Set how the image is resized to fit the size set with [Image::set_width] and [Image::set_height].
+    pub fn set_object_fit(&self, object_fit: ObjectFit, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        let object_fit_j = object_fit.get_java_value(env)?;
+        env.call_method(
+            self,
+            "setObjectFit",
+            "(Lcom/itextpdf/layout/properties/ObjectFit;)Lcom/itextpdf/layout/element/Image;",
+            &[(&object_fit_j).into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn get_image_scaled_width(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getImageScaledWidth", "()F", &[])?
+            .f()
+    }
+
+    pub fn get_image_scaled_height(&self, env: &mut JNIEnv<'a>) -> Result<f32> {
+        env.call_method(self, "getImageScaledHeight", "()F", &[])?
+            .f()
+    }
+
+    pub fn set_margin_top(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMarginTop",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[margin.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_margin_right(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMarginRight",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[margin.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_margin_bottom(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMarginBottom",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[margin.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_margin_left(&self, margin: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMarginLeft",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[margin.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_margins(
+        &self,
+        top: f32,
+        right: f32,
+        bottom: f32,
+        left: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setMargins",
+            "(FFFF)Lcom/itextpdf/layout/element/Image;",
+            &[top.into(), right.into(), bottom.into(), left.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_padding(&self, padding: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPadding",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[padding.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_padding_top(&self, padding: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPaddingTop",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[padding.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_padding_right(&self, padding: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPaddingRight",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[padding.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_padding_bottom(&self, padding: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPaddingBottom",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[padding.into()],
+        )?;
+        Ok(self)
+    }
+
+    pub fn set_padding_left(&self, padding: f32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setPaddingLeft",
+            "(F)Lcom/itextpdf/layout/element/Image;",
+            &[padding.into()],
+        )?;
+        Ok(self)
+    }
 }
 
 impl<'a> ElementPropertyContainer<'a> for Canvas<'a> {}
@@ -650,7 +1572,7 @@ impl<'a> Canvas<'a> {
     ) -> Result<Self> {
         let obj = env.new_object(
             "com/itextpdf/layout/Canvas",
-            "(Lcom/itextpdf/kernel/canvas/PdfCanvas;Lcom/itextpdf/kernel/geom/Rectangle;)V",
+            "(Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;Lcom/itextpdf/kernel/geom/Rectangle;)V",
             &[pdf_canvas.into(), root_area.into()],
         )?;
         Ok(Self(obj))
@@ -664,7 +1586,7 @@ impl<'a> Canvas<'a> {
     ) -> Result<Self> {
         let obj = env.new_object(
             "com/itextpdf/layout/Canvas",
-            "(Lcom/itextpdf/kernel/canvas/PdfCanvas;Lcom/itextpdf/kernel/geom/Rectangle;Z)V",
+            "(Lcom/itextpdf/kernel/pdf/canvas/PdfCanvas;Lcom/itextpdf/kernel/geom/Rectangle;Z)V",
             &[pdf_canvas.into(), root_area.into(), immediate_flush.into()],
         )?;
         Ok(Self(obj))
@@ -683,9 +1605,188 @@ impl<'a> Canvas<'a> {
         Ok(Self(obj))
     }
 
+    /// Create a canvas laying out elements into a form XObject.
+    pub fn new_from_form_x_object(
+        form_x_object: &PdfFormXObject<'a>,
+        pdf_document: &PdfDocument<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let obj = env.new_object(
+            "com/itextpdf/layout/Canvas",
+            "(Lcom/itextpdf/kernel/pdf/xobject/PdfFormXObject;Lcom/itextpdf/kernel/pdf/PdfDocument;)V",
+            &[form_x_object.into(), pdf_document.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Show a paragraph at `(x, y)`, aligned relative to that point and rotated by `rad_angle` radians.
+    #[allow(clippy::too_many_arguments)]
+    pub fn show_text_aligned(
+        &self,
+        paragraph: &Paragraph<'a>,
+        x: f32,
+        y: f32,
+        text_alignment: TextAlignment,
+        vertical_alignment: VerticalAlignment,
+        rad_angle: f32,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<&Self> {
+        let talign_j = text_alignment.get_java_value(env)?;
+        let valign_j = vertical_alignment.get_java_value(env)?;
+        env.call_method(
+            self,
+            "showTextAligned",
+            "(Lcom/itextpdf/layout/element/Paragraph;FFLcom/itextpdf/layout/properties/TextAlignment;Lcom/itextpdf/layout/properties/VerticalAlignment;F)Lcom/itextpdf/layout/IPropertyContainer;",
+            &[
+                paragraph.into(),
+                x.into(),
+                y.into(),
+                (&talign_j).into(),
+                (&valign_j).into(),
+                rad_angle.into(),
+            ],
+        )?;
+        Ok(self)
+    }
+
+    pub fn close(self, env: &mut JNIEnv<'a>) -> Result<()> {
+        env.call_method(&self, "close", "()V", &[])?;
+        Ok(())
+    }
+
     ///  Performs an entire recalculation of the element flow on the canvas, taking into account all its current child elements.
     pub fn relayout(&self, env: &mut JNIEnv<'a>) -> Result<&Self> {
         env.call_method(self, "relayout", "()V", &[])?;
         Ok(self)
     }
 }
+
+impl<'a> FontProvider<'a> {
+    pub fn new(env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object("com/itextpdf/layout/font/FontProvider", "()V", &[])?;
+        Ok(Self(obj))
+    }
+
+    /// Create a font provider with the fonts in `font_set`.
+    /// `default_font_family` is used when no font family is set on an element.
+    pub fn new_with_font_set(
+        font_set: &FontSet<'a>,
+        default_font_family: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let default_font_family = env.new_string(default_font_family)?;
+        let obj = env.new_object(
+            "com/itextpdf/layout/font/FontProvider",
+            "(Lcom/itextpdf/layout/font/FontSet;Ljava/lang/String;)V",
+            &[font_set.into(), (&default_font_family).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Add a font from the bytes of a font file. Returns `false` if the font was already added.
+    pub fn add_font_bytes(&self, bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<bool> {
+        let byte_array = new_byte_array(bytes, env)?;
+        env.call_method(self, "addFont", "([B)Z", &[(&byte_array).into()])?
+            .z()
+    }
+
+    /// Add a font from a font file. Returns `false` if the font was already added.
+    pub fn add_font_path(&self, path: &Path, env: &mut JNIEnv<'a>) -> Result<bool> {
+        let path = env.new_string(path.to_string_lossy())?;
+        env.call_method(self, "addFont", "(Ljava/lang/String;)Z", &[(&path).into()])?
+            .z()
+    }
+
+    /// Add a font program. Returns `false` if the font was already added.
+    pub fn add_font_program(
+        &self,
+        font_program: &FontProgram<'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<bool> {
+        env.call_method(
+            self,
+            "addFont",
+            "(Lcom/itextpdf/io/font/FontProgram;)Z",
+            &[font_program.into()],
+        )?
+        .z()
+    }
+
+    /// Add all fonts in a directory, optionally including its subdirectories. Returns the number of fonts added.
+    pub fn add_directory(
+        &self,
+        path: &Path,
+        scan_subdirectories: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<i32> {
+        // FontProvider.addDirectory(String) does not scan subdirectories
+        self.get_font_set(env)?
+            .add_directory(path, scan_subdirectories, env)
+    }
+
+    /// Add the 14 standard PDF fonts. Returns the number of fonts added.
+    pub fn add_standard_pdf_fonts(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "addStandardPdfFonts", "()I", &[])?
+            .i()
+    }
+
+    /// Add the fonts installed on the system. Returns the number of fonts added.
+    pub fn add_system_fonts(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "addSystemFonts", "()I", &[])?.i()
+    }
+
+    pub fn get_font_set(&self, env: &mut JNIEnv<'a>) -> Result<FontSet<'a>> {
+        let obj = env
+            .call_method(
+                self,
+                "getFontSet",
+                "()Lcom/itextpdf/layout/font/FontSet;",
+                &[],
+            )?
+            .l()?;
+        Ok(FontSet(obj))
+    }
+}
+
+impl<'a> FontSet<'a> {
+    pub fn new(env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object("com/itextpdf/layout/font/FontSet", "()V", &[])?;
+        Ok(Self(obj))
+    }
+
+    /// Add a font from the bytes of a font file. Returns `false` if the font was already added.
+    pub fn add_font_bytes(&self, bytes: &[u8], env: &mut JNIEnv<'a>) -> Result<bool> {
+        let byte_array = new_byte_array(bytes, env)?;
+        env.call_method(self, "addFont", "([B)Z", &[(&byte_array).into()])?
+            .z()
+    }
+
+    /// Add a font from a font file. Returns `false` if the font was already added.
+    pub fn add_font_path(&self, path: &Path, env: &mut JNIEnv<'a>) -> Result<bool> {
+        let path = env.new_string(path.to_string_lossy())?;
+        env.call_method(self, "addFont", "(Ljava/lang/String;)Z", &[(&path).into()])?
+            .z()
+    }
+
+    /// Add all fonts in a directory. Returns the number of fonts added.
+    pub fn add_directory(
+        &self,
+        path: &Path,
+        scan_subdirectories: bool,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<i32> {
+        let path = env.new_string(path.to_string_lossy())?;
+        env.call_method(
+            self,
+            "addDirectory",
+            "(Ljava/lang/String;Z)I",
+            &[(&path).into(), scan_subdirectories.into()],
+        )?
+        .i()
+    }
+
+    /// Get the number of fonts in the set.
+    pub fn size(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
+        env.call_method(self, "size", "()I", &[])?.i()
+    }
+}
@@ -1,4 +1,8 @@
 pub mod barcode;
+#[cfg(feature = "forms")]
+pub mod forms;
 pub mod io;
 pub mod kernel;
 pub mod layout;
+#[cfg(feature = "pdfua")]
+pub mod pdfua;
@@ -0,0 +1,130 @@
+use crate::itext::kernel::{PdfDocument, PdfWriter};
+use crate::java_object;
+use jni::objects::{JObject, JString};
+use jni::JNIEnv;
+use std::fmt;
+
+java_object!(PdfUADocument);
+
+#[derive(Clone)]
+pub enum PdfUAConformance {
+    PdfUA1,
+    PdfUA2,
+}
+
+impl PdfUAConformance {
+    fn get_java_value<'a>(&self, env: &mut JNIEnv<'a>) -> jni::errors::Result<JObject<'a>> {
+        let field_name = match self {
+            Self::PdfUA1 => "PDF_UA_1",
+            Self::PdfUA2 => "PDF_UA_2",
+        };
+
+        let obj = env
+            .get_static_field(
+                "com/itextpdf/kernel/pdf/PdfUAConformance",
+                field_name,
+                "Lcom/itextpdf/kernel/pdf/PdfUAConformance;",
+            )?
+            .l()?;
+        Ok(obj)
+    }
+}
+
+#[derive(Debug)]
+pub enum PdfUAError {
+    /// The document does not conform to PDF/UA, with iText's description of the violation.
+    Violation(String),
+    Jni(jni::errors::Error),
+}
+
+impl fmt::Display for PdfUAError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Violation(message) => write!(f, "PDF/UA violation: {message}"),
+            Self::Jni(e) => write!(f, "JNI error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PdfUAError {}
+
+impl From<jni::errors::Error> for PdfUAError {
+    fn from(value: jni::errors::Error) -> Self {
+        Self::Jni(value)
+    }
+}
+
+/// Convert a PDF/UA conformance exception thrown by iText into [PdfUAError::Violation].
+///
+/// Violations are reported while content is added to a [PdfUADocument], e.g. an image without alternate description,
+/// and when it is closed. Other Java exceptions are left pending.
+pub fn catch_violation<T>(
+    result: jni::errors::Result<T>,
+    env: &mut JNIEnv,
+) -> Result<T, PdfUAError> {
+    match result {
+        Err(jni::errors::Error::JavaException) => {
+            let exception = env.exception_occurred()?;
+            env.exception_clear()?;
+
+            if !env.is_instance_of(
+                &exception,
+                "com/itextpdf/pdfua/exceptions/PdfUAConformanceException",
+            )? {
+                env.throw(exception)?;
+                return Err(PdfUAError::Jni(jni::errors::Error::JavaException));
+            }
+
+            let message = env
+                .call_method(&exception, "getMessage", "()Ljava/lang/String;", &[])?
+                .l()?;
+            let message = if message.is_null() {
+                String::new()
+            } else {
+                env.get_string(&JString::from(message))?.into()
+            };
+            Err(PdfUAError::Violation(message))
+        }
+        result => Ok(result?),
+    }
+}
+
+impl<'a> PdfUADocument<'a> {
+    /// Create a tagged document, validating its conformance to PDF/UA. The title and language are required by PDF/UA.
+    pub fn new(
+        writer: &PdfWriter<'a>,
+        conformance: PdfUAConformance,
+        title: &str,
+        language: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> jni::errors::Result<Self> {
+        let conformance_j = conformance.get_java_value(env)?;
+        let title = env.new_string(title)?;
+        let language = env.new_string(language)?;
+        let config = env.new_object(
+            "com/itextpdf/pdfua/PdfUAConfig",
+            "(Lcom/itextpdf/kernel/pdf/PdfUAConformance;Ljava/lang/String;Ljava/lang/String;)V",
+            &[(&conformance_j).into(), (&title).into(), (&language).into()],
+        )?;
+
+        let obj = env.new_object(
+            "com/itextpdf/pdfua/PdfUADocument",
+            "(Lcom/itextpdf/kernel/pdf/PdfWriter;Lcom/itextpdf/pdfua/PdfUAConfig;)V",
+            &[writer.into(), (&config).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    /// Get the document as a [PdfDocument], e.g. to create a layout `Document` on it.
+    pub fn get_pdf_document(&self, env: &mut JNIEnv<'a>) -> jni::errors::Result<PdfDocument<'a>> {
+        let obj = env.new_local_ref(self)?;
+        Ok(PdfDocument(obj))
+    }
+
+    /// Close the document, validating the remaining PDF/UA requirements.
+    pub fn close(self, env: &mut JNIEnv<'a>) -> Result<(), PdfUAError> {
+        let result = env.call_method(&self, "close", "()V", &[]);
+        catch_violation(result, env)?;
+        Ok(())
+    }
+}
@@ -1,13 +1,16 @@
 use crate::java_object;
 use crate::javax::{ImageIO, ImageInputStream};
 use jni::errors::Result;
-use jni::objects::JByteArray;
+use jni::objects::{JByteArray, JIntArray, JObject};
 use jni::sys::{jbyte, jsize};
 use jni::JNIEnv;
+use std::fmt;
+use strum_macros::Display;
 
 java_object!(ByteArrayOutputStream);
 java_object!(ByteArrayInputStream);
 java_object!(BufferedImage);
+java_object!(AwtColor);
 
 impl<'a> ByteArrayOutputStream<'a> {
     pub fn new(env: &mut JNIEnv<'a>) -> Result<Self> {
@@ -43,7 +46,58 @@ impl<'a> ByteArrayInputStream<'a> {
     }
 }
 
+/// The pixel layout of a `BufferedImage`.
+#[derive(Clone, Display)]
+pub enum BufferedImageType {
+    IntRgb,
+    IntArgb,
+    IntArgbPre,
+    IntBgr,
+    ThreeByteBgr,
+    FourByteAbgr,
+    FourByteAbgrPre,
+    ByteGray,
+    ByteBinary,
+    ByteIndexed,
+    UshortGray,
+}
+
+impl BufferedImageType {
+    fn get_java_value(&self, env: &mut JNIEnv) -> Result<i32> {
+        let field_name = match self {
+            Self::IntRgb => "TYPE_INT_RGB",
+            Self::IntArgb => "TYPE_INT_ARGB",
+            Self::IntArgbPre => "TYPE_INT_ARGB_PRE",
+            Self::IntBgr => "TYPE_INT_BGR",
+            Self::ThreeByteBgr => "TYPE_3BYTE_BGR",
+            Self::FourByteAbgr => "TYPE_4BYTE_ABGR",
+            Self::FourByteAbgrPre => "TYPE_4BYTE_ABGR_PRE",
+            Self::ByteGray => "TYPE_BYTE_GRAY",
+            Self::ByteBinary => "TYPE_BYTE_BINARY",
+            Self::ByteIndexed => "TYPE_BYTE_INDEXED",
+            Self::UshortGray => "TYPE_USHORT_GRAY",
+        };
+        env.get_static_field("java/awt/image/BufferedImage", field_name, "I")?
+            .i()
+    }
+}
+
 impl<'a> BufferedImage<'a> {
+    pub fn new(
+        width: i32,
+        height: i32,
+        image_type: BufferedImageType,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Self> {
+        let image_type = image_type.get_java_value(env)?;
+        let obj = env.new_object(
+            "java/awt/image/BufferedImage",
+            "(III)V",
+            &[width.into(), height.into(), image_type.into()],
+        )?;
+        Ok(Self(obj))
+    }
+
     pub fn new_from_image_input_stream(
         image_input_stream: ImageInputStream<'a>,
         env: &mut JNIEnv<'a>,
@@ -51,6 +105,48 @@ impl<'a> BufferedImage<'a> {
         ImageIO::read_image_input_stream(image_input_stream, env)
     }
 
+    /// Draw any `java.awt.Image` onto a new ARGB `BufferedImage`.
+    pub(crate) fn from_awt_image(image: &JObject<'a>, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let null = JObject::null();
+        let width = env
+            .call_method(
+                image,
+                "getWidth",
+                "(Ljava/awt/image/ImageObserver;)I",
+                &[(&null).into()],
+            )?
+            .i()?;
+        let height = env
+            .call_method(
+                image,
+                "getHeight",
+                "(Ljava/awt/image/ImageObserver;)I",
+                &[(&null).into()],
+            )?
+            .i()?;
+        let image_type = env
+            .get_static_field("java/awt/image/BufferedImage", "TYPE_INT_ARGB", "I")?
+            .i()?;
+
+        let obj = env.new_object(
+            "java/awt/image/BufferedImage",
+            "(III)V",
+            &[width.into(), height.into(), image_type.into()],
+        )?;
+        let graphics = env
+            .call_method(&obj, "createGraphics", "()Ljava/awt/Graphics2D;", &[])?
+            .l()?;
+        env.call_method(
+            &graphics,
+            "drawImage",
+            "(Ljava/awt/Image;IILjava/awt/image/ImageObserver;)Z",
+            &[image.into(), 0.into(), 0.into(), (&null).into()],
+        )?;
+        env.call_method(&graphics, "dispose", "()V", &[])?;
+
+        Ok(Self(obj))
+    }
+
     pub fn get_width(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
         env.call_method(self, "getWidth", "()I", &[])?.i()
     }
@@ -58,4 +154,145 @@ impl<'a> BufferedImage<'a> {
     pub fn get_height(&self, env: &mut JNIEnv<'a>) -> Result<i32> {
         env.call_method(self, "getHeight", "()I", &[])?.i()
     }
+
+    /// Get a pixel as `0xAARRGGBB`, in the default sRGB color space.
+    pub fn get_rgb(&self, x: i32, y: i32, env: &mut JNIEnv<'a>) -> Result<u32> {
+        let argb = env
+            .call_method(self, "getRGB", "(II)I", &[x.into(), y.into()])?
+            .i()?;
+        Ok(argb as u32)
+    }
+
+    /// Set a pixel from `0xAARRGGBB`, in the default sRGB color space.
+    pub fn set_rgb(&self, x: i32, y: i32, argb: u32, env: &mut JNIEnv<'a>) -> Result<&Self> {
+        env.call_method(
+            self,
+            "setRGB",
+            "(III)V",
+            &[x.into(), y.into(), (argb as i32).into()],
+        )?;
+        Ok(self)
+    }
+
+    /// Get all pixels as `0xAARRGGBB`, row by row from the top left.
+    pub fn get_pixels(&self, env: &mut JNIEnv<'a>) -> Result<Vec<u32>> {
+        let width = self.get_width(env)?;
+        let height = self.get_height(env)?;
+
+        let array: JIntArray = env
+            .call_method(
+                self,
+                "getRGB",
+                "(IIII[III)[I",
+                &[
+                    0.into(),
+                    0.into(),
+                    width.into(),
+                    height.into(),
+                    (&JObject::null()).into(),
+                    0.into(),
+                    width.into(),
+                ],
+            )?
+            .l()?
+            .into();
+
+        let mut buf = vec![0; width as usize * height as usize];
+        env.get_int_array_region(&array, 0, &mut buf)?;
+        Ok(buf.into_iter().map(|x| x as u32).collect())
+    }
+
+    /// Set all pixels from `0xAARRGGBB`, row by row from the top left.
+    /// The number of pixels must match the dimensions of the image.
+    pub fn set_pixels(
+        &self,
+        pixels: &[u32],
+        env: &mut JNIEnv<'a>,
+    ) -> std::result::Result<&Self, PixelCountError> {
+        let width = self.get_width(env)?;
+        let height = self.get_height(env)?;
+        let expected = width as usize * height as usize;
+        if pixels.len() != expected {
+            return Err(PixelCountError::Mismatch {
+                expected,
+                count: pixels.len(),
+            });
+        }
+
+        let array = env.new_int_array(pixels.len() as jsize)?;
+        env.set_int_array_region(
+            &array,
+            0,
+            &pixels.iter().map(|x| *x as i32).collect::<Vec<_>>(),
+        )?;
+        env.call_method(
+            self,
+            "setRGB",
+            "(IIII[III)V",
+            &[
+                0.into(),
+                0.into(),
+                width.into(),
+                height.into(),
+                (&array).into(),
+                0.into(),
+                width.into(),
+            ],
+        )?;
+        Ok(self)
+    }
+}
+
+#[derive(Debug)]
+pub enum PixelCountError {
+    /// The number of pixels does not match the dimensions of the image.
+    Mismatch {
+        expected: usize,
+        count: usize,
+    },
+    Jni(jni::errors::Error),
+}
+
+impl fmt::Display for PixelCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mismatch { expected, count } => {
+                write!(f, "Expected {expected} pixels, got {count}")
+            }
+            Self::Jni(e) => write!(f, "JNI error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PixelCountError {}
+
+impl From<jni::errors::Error> for PixelCountError {
+    fn from(value: jni::errors::Error) -> Self {
+        Self::Jni(value)
+    }
+}
+
+impl<'a> AwtColor<'a> {
+    pub fn from_rgb(r: u8, g: u8, b: u8, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "java/awt/Color",
+            "(III)V",
+            &[(r as i32).into(), (g as i32).into(), (b as i32).into()],
+        )?;
+        Ok(Self(obj))
+    }
+
+    pub fn from_rgba(r: u8, g: u8, b: u8, a: u8, env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object(
+            "java/awt/Color",
+            "(IIII)V",
+            &[
+                (r as i32).into(),
+                (g as i32).into(),
+                (b as i32).into(),
+                (a as i32).into(),
+            ],
+        )?;
+        Ok(Self(obj))
+    }
 }
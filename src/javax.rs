@@ -1,4 +1,4 @@
-use crate::java::{BufferedImage, ByteArrayInputStream};
+use crate::java::{BufferedImage, ByteArrayInputStream, ByteArrayOutputStream};
 use crate::java_object;
 use jni::errors::Result;
 use jni::JNIEnv;
@@ -38,6 +38,34 @@ impl<'a> ImageIO<'a> {
     }
 }
 
+impl<'a> ImageIO<'a> {
+    /// Encode the image in the given format, e.g. `png` or `jpeg`.
+    /// Returns `None` if no writer is available for the format and image type.
+    /// Note that the JPEG writer does not support images with an alpha channel.
+    pub fn write(
+        image: &BufferedImage<'a>,
+        format_name: &str,
+        env: &mut JNIEnv<'a>,
+    ) -> Result<Option<Vec<u8>>> {
+        let byte_stream = ByteArrayOutputStream::new(env)?;
+        let format_name = env.new_string(format_name)?;
+        let written = env
+            .call_static_method(
+                "javax/imageio/ImageIO",
+                "write",
+                "(Ljava/awt/image/RenderedImage;Ljava/lang/String;Ljava/io/OutputStream;)Z",
+                &[image.into(), (&format_name).into(), (&byte_stream).into()],
+            )?
+            .z()?;
+
+        if written {
+            Ok(Some(byte_stream.to_byte_array(env)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 impl<'a> ImageInputStream<'a> {
     pub fn new_from_byte_stream(
         byte_stream: ByteArrayInputStream<'a>,